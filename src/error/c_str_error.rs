@@ -0,0 +1,61 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::LengthError;
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A string could not be viewed as a C string.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub enum CStrError {
+	/// The string contained a null character before its end.
+	///
+	/// The contained value denotes the octet index of the null character.
+	InteriorNul(usize),
+
+	/// The string did not end in a null character.
+	NotNulTerminated,
+
+	/// The buffer could not hold a null terminator.
+	SmallBuffer(LengthError),
+}
+
+impl Display for CStrError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InteriorNul(index) => write!(f, "found null character at offset ({index})"),
+
+			Self::NotNulTerminated => write!(f, "string is not null-terminated"),
+
+			Self::SmallBuffer(ref e) => write!(f, "unable to hold null terminator: {e}"),
+		}
+	}
+}
+
+impl Error for CStrError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::SmallBuffer(ref e) => Some(e),
+
+			_ => None,
+		}
+	}
+}
+
+impl From<Infallible> for CStrError {
+	#[inline(always)]
+	fn from(_value: Infallible) -> Self {
+		unreachable!()
+	}
+}
+
+impl From<LengthError> for CStrError {
+	#[inline(always)]
+	fn from(value: LengthError) -> Self {
+		Self::SmallBuffer(value)
+	}
+}
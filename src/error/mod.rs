@@ -2,8 +2,14 @@
 
 //! Error types.
 
+mod c_str_error;
 mod length_error;
+mod parse_error;
+mod string_error;
 mod utf8_error;
 
+pub use c_str_error::CStrError;
 pub use length_error::LengthError;
+pub use parse_error::ParseError;
+pub use string_error::StringError;
 pub use utf8_error::Utf8Error;
@@ -0,0 +1,62 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::LengthError;
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::num::ParseIntError;
+
+/// A string could not be parsed.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub enum ParseError {
+	/// A field could not be parsed as an integer.
+	BadField(ParseIntError),
+
+	/// The output buffer could not hold all fields.
+	SmallBuffer(LengthError),
+}
+
+impl Display for ParseError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadField(ref e) => write!(f, "unable to parse field: {e}"),
+
+			Self::SmallBuffer(ref e) => write!(f, "unable to hold fields: {e}"),
+		}
+	}
+}
+
+impl Error for ParseError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadField(ref e) => Some(e),
+
+			Self::SmallBuffer(ref e) => Some(e),
+		}
+	}
+}
+
+impl From<Infallible> for ParseError {
+	#[inline(always)]
+	fn from(_value: Infallible) -> Self {
+		unreachable!()
+	}
+}
+
+impl From<LengthError> for ParseError {
+	#[inline(always)]
+	fn from(value: LengthError) -> Self {
+		Self::SmallBuffer(value)
+	}
+}
+
+impl From<ParseIntError> for ParseError {
+	#[inline(always)]
+	fn from(value: ParseIntError) -> Self {
+		Self::BadField(value)
+	}
+}
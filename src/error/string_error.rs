@@ -0,0 +1,88 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::{LengthError, Utf8Error};
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "embedded-io")]
+use embedded_io::ErrorKind;
+
+/// A string could not be constructed.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub enum StringError {
+	/// The string could not fit into its buffer.
+	Length(LengthError),
+
+	/// The string contained invalid UTF-8.
+	Utf8(Utf8Error),
+
+	/// The string ended in an incomplete UTF-8 sequence.
+	///
+	/// Contrary to [`Utf8`](Self::Utf8), the sequence may still be valid once its remaining octets are available.
+	Incomplete(Utf8Error),
+}
+
+impl Display for StringError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Length(ref e) => write!(f, "unable to contain string: {e}"),
+
+			Self::Utf8(ref e) => write!(f, "unable to decode string: {e}"),
+
+			Self::Incomplete(ref e) => write!(f, "unable to decode incomplete string: {e}"),
+		}
+	}
+}
+
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(doc, doc(cfg(feature = "embedded-io")))]
+impl embedded_io::Error for StringError {
+	#[inline]
+	fn kind(&self) -> ErrorKind {
+		match *self {
+			Self::Length(..) => ErrorKind::WriteZero,
+
+			Self::Utf8(..) => ErrorKind::InvalidData,
+
+			// NOTE: An incomplete sequence may be retried
+			// once more octets are available.
+			Self::Incomplete(..) => ErrorKind::Interrupted,
+		}
+	}
+}
+
+impl Error for StringError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::Length(ref e) => Some(e),
+
+			Self::Utf8(ref e) | Self::Incomplete(ref e) => Some(e),
+		}
+	}
+}
+
+impl From<Infallible> for StringError {
+	#[inline(always)]
+	fn from(_value: Infallible) -> Self {
+		unreachable!()
+	}
+}
+
+impl From<LengthError> for StringError {
+	#[inline(always)]
+	fn from(value: LengthError) -> Self {
+		Self::Length(value)
+	}
+}
+
+impl From<Utf8Error> for StringError {
+	#[inline(always)]
+	fn from(value: Utf8Error) -> Self {
+		Self::Utf8(value)
+	}
+}
@@ -15,11 +15,17 @@ extern crate alloc;
 extern crate std;
 
 pub mod error;
+pub mod utf8;
 
 mod string;
-mod utf8;
 
-pub use string::{__string, String};
+pub use string::{__match_string, __string, AsciiCaseInsensitive, BytesMutGuard, CharBoundaries, IntoChars, Lines, Split, String, max_capacity, min_capacity};
+
+#[cfg(feature = "oct")]
+pub use string::{FixedString, ShortString};
+
+#[cfg(feature = "rkyv")]
+pub use string::ArchivedString;
 
 /// Directly constructs a [`String`](crate::string::String) object.
 ///
@@ -35,3 +41,120 @@ macro_rules! string {
 		const { conststr::__string("") }
 	};
 }
+
+/// Concatenates string expressions into a [`String`](crate::string::String) object.
+///
+/// All expressions are evaluated at compile-time, and compilation will fail if the concatenated string cannot fit into the specified type.
+///
+/// # Examples
+///
+/// ```rust
+/// use conststr::{String, const_concat};
+///
+/// const HOST: &str = "example.com";
+///
+/// let url = const_concat!(String<0x20>; "https://", HOST, "/");
+/// assert_eq!(url, "https://example.com/");
+/// ```
+///
+/// Concatenating too many strings does not compile:
+///
+/// ```rust,compile_fail
+/// use conststr::{String, const_concat};
+///
+/// let s = const_concat!(String<0x4>; "ab", "cd", "e");
+/// ```
+#[macro_export]
+macro_rules! const_concat {
+	($ty:ty; $($s:expr),* $(,)?) => {
+		const {
+			#[allow(unused_mut)]
+			let mut s: $ty = conststr::String::new();
+
+			$(
+				if s.push_str($s).is_err() {
+					panic!("cannot concatenate strings that are longer");
+				}
+			)*
+
+			s
+		}
+	};
+}
+
+/// Formats arguments into a [`String`](crate::string::String) object.
+///
+/// The syntax is the same as that of [`format!`](std::format), except that the type of the string must be specified first.
+/// Unlike [`const_concat`], the arguments are formatted at run-time, and the macro evaluates to a [`Result`] instead of panicking.
+///
+/// # Errors
+///
+/// If the formatted string cannot fit into the specified type, then [`fmt::Error`](core::fmt::Error) is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use conststr::{String, format_into};
+///
+/// let (major, minor) = (0x1, 0x2);
+///
+/// let version = format_into!(String<0x8>, "{major}.{minor}").unwrap();
+/// assert_eq!(version, "1.2");
+///
+/// assert!(format_into!(String<0x2>, "{major}.{minor}").is_err());
+/// ```
+#[macro_export]
+macro_rules! format_into {
+	($ty:ty, $($args:tt)*) => {{
+		let mut s: $ty = conststr::String::new();
+
+		::core::fmt::Write::write_fmt(&mut s, ::core::format_args!($($args)*)).map(|()| s)
+	}};
+}
+
+/// Maps a [`String`](crate::string::String) object to a value using string literal patterns.
+///
+/// Each arm is compared in order -- first by length and then octet by octet -- and the first matching arm is evaluated.
+/// If no arm matches, then the final wildcard arm is evaluated instead.
+/// Multiple literals may be combined in a single arm using `|`.
+///
+/// As the comparisons are done without the use of traits, this macro may also be used in constant expressions.
+///
+/// # Examples
+///
+/// ```rust
+/// use conststr::{String, match_string, string};
+///
+/// #[derive(Debug, Eq, PartialEq)]
+/// enum Command {
+///     Start,
+///     Stop,
+///     Unknown,
+/// }
+///
+/// const fn parse_command(s: &String<0x10>) -> Command {
+///     match_string!(s, {
+///         "start" | "begin" => Command::Start,
+///         "stop"            => Command::Stop,
+///         _                 => Command::Unknown,
+///     })
+/// }
+///
+/// assert_eq!(parse_command(&string!("start")), Command::Start);
+/// assert_eq!(parse_command(&string!("stop")),  Command::Stop);
+/// assert_eq!(parse_command(&string!("pause")), Command::Unknown);
+/// ```
+#[macro_export]
+macro_rules! match_string {
+	($s:expr, { $($($pattern:literal)|+ => $arm:expr,)* _ => $default:expr $(,)? }) => {{
+		let s = &$s;
+
+		$(
+			if $(conststr::__match_string(s, $pattern))||+ {
+				$arm
+			} else
+		)* {
+			$default
+		}
+	}};
+}
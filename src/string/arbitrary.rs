@@ -0,0 +1,40 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "arbitrary")]
+
+use crate::String;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+#[cfg_attr(doc, doc(cfg(feature = "arbitrary")))]
+impl<'a, const N: usize> Arbitrary<'a> for String<N> {
+	/// Generates a string of at most `N` octets.
+	///
+	/// Characters are pushed one by one until either the generated count is reached or the string is full.
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+		let mut this = Self::new();
+
+		let count = u.arbitrary_len::<char>()?.min(N);
+
+		for _ in 0x0..count {
+			let c = char::arbitrary(u)?;
+
+			if this.push(c).is_err() {
+				break;
+			}
+		}
+
+		Ok(this)
+	}
+
+	#[inline]
+	fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+		// NOTE: The length is encoded using at most
+		// eight octets, and each character is decoded
+		// from exactly four octets.
+		let max = size_of::<u64>() + size_of::<char>() * N;
+
+		(0x0, Some(max))
+	}
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// ASCII case-insensitive comparison adapter.
+///
+/// This type wraps a string (usually a [`String`](crate::String)) and implements [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`], and [`Hash`] as if all ASCII letters were lowercase.
+/// Non-ASCII octets are compared as is.
+///
+/// Hashing is consistent with equality, making this type suitable as a key in hash maps.
+///
+/// # Examples
+///
+/// ```rust
+/// use conststr::{AsciiCaseInsensitive, String, string};
+///
+/// let a: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("Key"));
+/// let b: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("kEY"));
+///
+/// assert_eq!(a, b);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct AsciiCaseInsensitive<T>(T);
+
+impl<T> AsciiCaseInsensitive<T> {
+	/// Wraps a string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(s: T) -> Self {
+		Self(s)
+	}
+
+	/// Unwraps the string.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T: Debug> Debug for AsciiCaseInsensitive<T> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.0, f)
+	}
+}
+
+impl<T> Deref for AsciiCaseInsensitive<T> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<T> DerefMut for AsciiCaseInsensitive<T> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<T: Display> Display for AsciiCaseInsensitive<T> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl<T: AsRef<[u8]>> Eq for AsciiCaseInsensitive<T> { }
+
+impl<T> From<T> for AsciiCaseInsensitive<T> {
+	#[inline(always)]
+	fn from(value: T) -> Self {
+		Self(value)
+	}
+}
+
+impl<T: AsRef<[u8]>> Hash for AsciiCaseInsensitive<T> {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		for &octet in self.0.as_ref() {
+			state.write_u8(octet.to_ascii_lowercase());
+		}
+
+		// NOTE: This mirrors the terminator written by
+		// `str` to avoid prefix collisions.
+		state.write_u8(0xFF);
+	}
+}
+
+impl<T: AsRef<[u8]>> Ord for AsciiCaseInsensitive<T> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		let this  = self.0.as_ref().iter().map(u8::to_ascii_lowercase);
+		let other = other.0.as_ref().iter().map(u8::to_ascii_lowercase);
+
+		this.cmp(other)
+	}
+}
+
+impl<T: AsRef<[u8]>> PartialEq for AsciiCaseInsensitive<T> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<[u8]>> PartialOrd for AsciiCaseInsensitive<T> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
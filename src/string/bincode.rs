@@ -0,0 +1,61 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "bincode")]
+
+use crate::String;
+
+use bincode::{BorrowDecode, Decode, Encode};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::de::read::Reader;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use core::str;
+
+#[cfg_attr(doc, doc(cfg(feature = "bincode")))]
+impl<'de, Context, const N: usize> BorrowDecode<'de, Context> for String<N> {
+	#[inline(always)]
+	fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		Self::decode(decoder)
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "bincode")))]
+impl<Context, const N: usize> Decode<Context> for String<N> {
+	/// Decodes using the same format as [`encode`](Encode::encode).
+	///
+	/// # Errors
+	///
+	/// If the decoded string is longer than `N` octets, then an [`Other`](DecodeError::Other) error is returned.
+	/// If it contains invalid UTF-8, then a [`Utf8`](DecodeError::Utf8) error is returned.
+	#[inline]
+	fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		let len = usize::decode(decoder)?;
+
+		if len > N {
+			return Err(DecodeError::Other("cannot decode string that is longer than its capacity"));
+		}
+
+		decoder.claim_bytes_read(len)?;
+
+		let mut buf = [0x00; N];
+		decoder.reader().read(&mut buf[..len])?;
+
+		if let Err(e) = str::from_utf8(&buf[..len]) {
+			return Err(DecodeError::Utf8 { inner: e });
+		}
+
+		// SAFETY: We have tested that the first `len` oct-
+		// ets are valid UTF-8.
+		let this = unsafe { Self::from_raw_parts(buf, len) };
+		Ok(this)
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "bincode")))]
+impl<const N: usize> Encode for String<N> {
+	/// Encodes using the same format as <code>&lt;[prim@str] as Encode&gt;::encode</code>.
+	#[inline]
+	fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+		self.as_str().encode(encoder)
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::String;
+
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::str;
+
+/// Guard for mutably borrowing a string as octets.
+///
+/// This guard is returned by [`String::bytes_mut_guard`] and dereferences to the used octets of the string.
+/// Arbitrary octets may be written through it.
+///
+/// When the guard is dropped, the string is validated as UTF-8.
+/// If any invalid UTF-8 was written, then the string is truncated to the longest prefix that is still valid.
+/// Truncation was chosen over panicking so that byte-level transforms can never abort the program.
+///
+/// The string is considered empty whilst the guard exists.
+/// If the guard is leaked (e.g. with [`mem::forget`](core::mem::forget)), then the string will therefore remain empty.
+#[must_use]
+pub struct BytesMutGuard<'a, const N: usize> {
+	string: &'a mut String<N>,
+	len:    usize,
+}
+
+impl<'a, const N: usize> BytesMutGuard<'a, N> {
+	#[inline]
+	pub(super) const fn new(string: &'a mut String<N>) -> Self {
+		let len = string.len();
+
+		// NOTE: The string is emptied for as long as the
+		// guard lives, so that leaking it cannot expose
+		// invalid UTF-8.
+		string.len = 0x0;
+
+		Self { string, len }
+	}
+}
+
+impl<const N: usize> Debug for BytesMutGuard<'_, N> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&**self, f)
+	}
+}
+
+impl<const N: usize> Deref for BytesMutGuard<'_, N> {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		let (buf, _) = self.string.buf.split_at(self.len);
+		buf
+	}
+}
+
+impl<const N: usize> DerefMut for BytesMutGuard<'_, N> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		let (buf, _) = self.string.buf.split_at_mut(self.len);
+		buf
+	}
+}
+
+impl<const N: usize> Drop for BytesMutGuard<'_, N> {
+	#[inline]
+	fn drop(&mut self) {
+		let len = match str::from_utf8(self) {
+			Ok(..) => self.len,
+
+			Err(e) => e.valid_up_to(),
+		};
+
+		self.string.len = len;
+		self.string.debug_assert_invariants();
+	}
+}
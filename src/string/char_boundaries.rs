@@ -0,0 +1,70 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::String;
+use crate::utf8::utf8_char_len;
+
+use core::iter::FusedIterator;
+
+/// Iterator over the character boundaries of a string.
+///
+/// This iterator is returned by [`String::char_boundaries`].
+/// Each yielded value is an octet index for which [`String::is_char_boundary`] holds, including zero and the length of the string.
+///
+/// Boundaries may also be iterated in constant expressions using [`next_boundary`](Self::next_boundary).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CharBoundaries<'a, const N: usize> {
+	string: &'a String<N>,
+	index:  Option<usize>,
+}
+
+impl<'a, const N: usize> CharBoundaries<'a, N> {
+	#[inline(always)]
+	pub(super) const fn new(string: &'a String<N>) -> Self {
+		Self { string, index: Some(0x0) }
+	}
+
+	/// Yields the next boundary.
+	///
+	/// This is equivalent to [`Iterator::next`], but may also be used in constant expressions.
+	#[inline]
+	pub const fn next_boundary(&mut self) -> Option<usize> {
+		let Some(index) = self.index else {
+			return None;
+		};
+
+		let buf = self.string.as_bytes();
+
+		self.index = if index < buf.len() {
+			Some(index + utf8_char_len(buf[index]))
+		} else {
+			None
+		};
+
+		Some(index)
+	}
+}
+
+impl<const N: usize> FusedIterator for CharBoundaries<'_, N> { }
+
+impl<const N: usize> Iterator for CharBoundaries<'_, N> {
+	type Item = usize;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.next_boundary()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let Some(index) = self.index else {
+			return (0x0, Some(0x0));
+		};
+
+		// Each character is between one and four octets
+		// long, and the final boundary is always yielded.
+		let remaining = self.string.len() - index;
+
+		(remaining.div_ceil(0x4) + 0x1, Some(remaining + 0x1))
+	}
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "embedded-io")]
+
+use crate::String;
+use crate::error::{LengthError, StringError, Utf8Error};
+
+use core::str;
+use embedded_io::{ErrorType, Write};
+
+#[cfg_attr(doc, doc(cfg(feature = "embedded-io")))]
+impl<const N: usize> ErrorType for String<N> {
+	type Error = StringError;
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "embedded-io")))]
+impl<const N: usize> Write for String<N> {
+	/// Writes the longest prefix of `buf` that is valid UTF-8 and fits into the string.
+	///
+	/// Characters are never split, meaning that partial characters are not written.
+	///
+	/// # Errors
+	///
+	/// If not even the first character of `buf` fits into the string, then a [`Length`](StringError::Length) error is returned.
+	///
+	/// If `buf` starts with an invalid UTF-8 sequence, then a [`Utf8`](StringError::Utf8) error is returned.
+	/// The data is corrupt and retrying the write will not succeed.
+	///
+	/// If `buf` only contains the start of an otherwise valid UTF-8 sequence, then an [`Incomplete`](StringError::Incomplete) error is returned instead.
+	/// Its kind is [`Interrupted`](embedded_io::ErrorKind::Interrupted), and the write should be retried once the remaining octets of the sequence have been appended to `buf`.
+	/// Note that the sequence is only reported as incomplete if no preceding characters could be written: otherwise, the preceding characters are written and the sequence is left for the next call.
+	#[inline]
+	fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+		if buf.is_empty() {
+			return Ok(0x0);
+		}
+
+		let (valid, is_incomplete) = match str::from_utf8(buf) {
+			Ok(s) => (s, false),
+
+			Err(e) => {
+				let (valid, _) = buf.split_at(e.valid_up_to());
+
+				// SAFETY: `valid` has been tested to only
+				// contain valid UTF-8.
+				let valid = unsafe { str::from_utf8_unchecked(valid) };
+
+				// NOTE: Sequences that are cut off by the end
+				// of `buf` do not have an error length.
+				(valid, e.error_len().is_none())
+			}
+		};
+
+		let remaining = N - self.len();
+
+		let mut mid = valid.len().min(remaining);
+		while !valid.is_char_boundary(mid) {
+			mid -= 0x1;
+		}
+
+		if mid == 0x0 {
+			if let Some(c) = valid.chars().next() {
+				return Err(LengthError {
+					remaining,
+					count: c.len_utf8(),
+				}.into());
+			}
+
+			let e = Utf8Error {
+				value: buf[0x0],
+				index: 0x0,
+			};
+
+			if is_incomplete {
+				return Err(StringError::Incomplete(e));
+			}
+
+			return Err(e.into());
+		}
+
+		let (s, _) = valid.split_at(mid);
+		self.push_str(s)?;
+
+		Ok(mid)
+	}
+
+	#[inline(always)]
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "oct")]
+
+use crate::String;
+use crate::error::Utf8Error;
+
+use core::ops::{Deref, DerefMut};
+use core::str;
+use oct::decode::{self, Decode};
+use oct::encode::{self, Encode, SizedEncode};
+
+/// Fixed-width encoding adapter for [`String`].
+///
+/// Contrary to [`String`] itself, this type encodes to exactly `N` octets without a length prefix.
+/// Any unused octets are encoded as null.
+///
+/// When decoding, trailing null octets are removed to determine the length of the string.
+/// Strings that themselves end in null characters can therefore not be round-tripped.
+#[cfg_attr(doc, doc(cfg(feature = "oct")))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct FixedString<const N: usize>(String<N>);
+
+impl<const N: usize> FixedString<N> {
+	/// Wraps a string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(s: String<N>) -> Self {
+		Self(s)
+	}
+
+	/// Unwraps the string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn into_inner(self) -> String<N> {
+		self.0
+	}
+}
+
+impl<const N: usize> Decode for FixedString<N> {
+	type Error = Utf8Error;
+
+	#[inline]
+	#[track_caller]
+	fn decode(input: &mut decode::Input) -> Result<Self, Self::Error> {
+		let mut buf = [0x00; N];
+		input.read_into(&mut buf);
+
+		let mut len = N;
+		while len > 0x0 && buf[len - 0x1] == 0x00 {
+			len -= 0x1;
+		}
+
+		if let Err(e) = str::from_utf8(&buf[..len]) {
+			let i = e.valid_up_to();
+			let c = buf[i];
+
+			return Err(Utf8Error { value: c, index: i });
+		}
+
+		// SAFETY: We have tested that the first `len`
+		// octets are valid UTF-8.
+		let s = unsafe { String::from_raw_parts(buf, len) };
+		Ok(Self(s))
+	}
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+	type Target = String<N>;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<const N: usize> DerefMut for FixedString<N> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<const N: usize> Encode for FixedString<N> {
+	type Error = <[u8; N] as Encode>::Error;
+
+	/// Encodes the string as exactly `N` octets.
+	///
+	/// Unused octets are encoded as null.
+	#[inline]
+	#[track_caller]
+	fn encode(&self, output: &mut encode::Output) -> Result<(), Self::Error> {
+		let mut buf = [0x00; N];
+		buf[..self.len()].copy_from_slice(self.as_bytes());
+
+		buf.encode(output)
+	}
+}
+
+impl<const N: usize> From<String<N>> for FixedString<N> {
+	#[inline(always)]
+	fn from(value: String<N>) -> Self {
+		Self(value)
+	}
+}
+
+impl<const N: usize> From<FixedString<N>> for String<N> {
+	#[inline(always)]
+	fn from(value: FixedString<N>) -> Self {
+		value.0
+	}
+}
+
+impl<const N: usize> SizedEncode for FixedString<N> {
+	const MAX_ENCODED_SIZE: usize = <[u8; N] as SizedEncode>::MAX_ENCODED_SIZE;
+}
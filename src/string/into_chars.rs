@@ -0,0 +1,65 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::String;
+use crate::utf8::decode_utf8;
+
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+
+/// Owning iterator over the characters of a string.
+///
+/// This iterator is returned by [`String::into_chars`].
+/// Characters are decoded lazily as they are yielded.
+#[derive(Clone)]
+#[must_use]
+pub struct IntoChars<const N: usize> {
+	string: String<N>,
+	index:  usize,
+}
+
+impl<const N: usize> IntoChars<N> {
+	#[inline(always)]
+	pub(super) const fn new(string: String<N>) -> Self {
+		Self { string, index: 0x0 }
+	}
+
+	/// Borrows the remaining characters as a string slice.
+	#[inline]
+	#[must_use]
+	pub const fn as_str(&self) -> &str {
+		let (_, s) = self.string.split_at(self.index);
+		s
+	}
+}
+
+impl<const N: usize> Debug for IntoChars<N> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("IntoChars").field(&self.as_str()).finish()
+	}
+}
+
+impl<const N: usize> FusedIterator for IntoChars<N> { }
+
+impl<const N: usize> Iterator for IntoChars<N> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index >= self.string.len() {
+			return None;
+		}
+
+		let (c, len) = decode_utf8(self.string.as_str(), self.index);
+		self.index += len;
+
+		Some(c)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.string.len() - self.index;
+
+		(remaining.div_ceil(0x4), Some(remaining))
+	}
+}
@@ -0,0 +1,70 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::String;
+
+use core::iter::FusedIterator;
+
+/// Iterator over the lines of a string.
+///
+/// This iterator is returned by [`String::lines_iter`].
+/// Lines are split as per [`str::lines`].
+///
+/// Contrary to [`str::Lines`](core::str::Lines), lines may also be iterated in constant expressions using [`next_line`](Self::next_line).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Lines<'a, const N: usize> {
+	string: &'a String<N>,
+	index:  usize,
+}
+
+impl<'a, const N: usize> Lines<'a, N> {
+	#[inline(always)]
+	pub(super) const fn new(string: &'a String<N>) -> Self {
+		Self { string, index: 0x0 }
+	}
+
+	/// Yields the next line.
+	///
+	/// This is equivalent to [`Iterator::next`], but may also be used in constant expressions.
+	#[inline]
+	pub const fn next_line(&mut self) -> Option<&'a str> {
+		let buf = self.string.as_bytes();
+		let len = buf.len();
+
+		let start = self.index;
+
+		if start >= len {
+			return None;
+		}
+
+		let mut end = start;
+		while end < len && buf[end] != b'\n' {
+			end += 0x1;
+		}
+
+		// Skip the line feed, if any.
+		self.index = if end < len { end + 0x1 } else { end };
+
+		// Strip a carriage return, but only if it pre-
+		// cedes a line feed.
+		if end < len && end > start && buf[end - 0x1] == b'\r' {
+			end -= 0x1;
+		}
+
+		let (line, _) = self.string.as_str().split_at(end);
+		let (_, line) = line.split_at(start);
+
+		Some(line)
+	}
+}
+
+impl<const N: usize> FusedIterator for Lines<'_, N> { }
+
+impl<'a, const N: usize> Iterator for Lines<'a, N> {
+	type Item = &'a str;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.next_line()
+	}
+}
@@ -2,19 +2,53 @@
 
 mod test;
 
+mod ascii_case_insensitive;
+mod bytes_mut_guard;
+mod char_boundaries;
+mod into_chars;
+mod lines;
+mod split;
+
+mod arbitrary;
+mod bincode;
+mod embedded_io;
+mod fixed_string;
+mod rkyv;
 mod serde;
+mod short_string;
+mod zeroize;
 
-use crate::error::{LengthError, Utf8Error};
-use crate::utf8::decode_utf8;
+pub use self::ascii_case_insensitive::AsciiCaseInsensitive;
+pub use self::bytes_mut_guard::BytesMutGuard;
+pub use self::char_boundaries::CharBoundaries;
+pub use self::into_chars::IntoChars;
+pub use self::lines::Lines;
+pub use self::split::Split;
+
+#[cfg(feature = "oct")]
+pub use self::fixed_string::FixedString;
+
+#[cfg(feature = "oct")]
+pub use self::short_string::ShortString;
+
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedString;
+
+use crate::error::{CStrError, LengthError, ParseError, StringError, Utf8Error};
+use crate::utf8::{count_invalid_utf8, decode_utf8, is_whitespace, trim_end, trim_start, utf8_char_len};
 
 use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
+use core::ffi::CStr;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
-use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::iter;
+use core::num::ParseIntError;
+use core::ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds};
 use core::ptr::{copy, copy_nonoverlapping};
 use core::slice::{self, SliceIndex};
-use core::str::{self, FromStr};
+use core::str::{self, CharIndices, Chars, FromStr};
+use core::time::Duration;
 
 #[cfg(feature = "alloc")]
 use {
@@ -22,6 +56,9 @@ use {
 	alloc::boxed::Box,
 };
 
+#[cfg(feature = "defmt")]
+use defmt::Format;
+
 #[cfg(feature = "oct")]
 use {
 	oct::decode::{self, Decode, DecodeBorrowed},
@@ -36,6 +73,12 @@ use {
 	std::path::Path,
 };
 
+#[cfg(feature = "ufmt")]
+use ufmt::{uDisplay, uWrite};
+
+#[cfg(feature = "unicode-width")]
+use unicode_width::UnicodeWidthChar;
+
 /// String container with maximum length.
 ///
 /// This is in contrast to [`str`](prim@str) and the standard library's [`String`](alloc::string::String) type -- both of which have no size limit in practice.
@@ -71,6 +114,27 @@ pub struct String<const N: usize> {
 }
 
 impl<const N: usize> String<N> {
+	/// The capacity of the string, in octets.
+	///
+	/// This is always equal to `N` and may be used in constant expressions, e.g. together with [`max_capacity`] and [`min_capacity`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use conststr::{String, max_capacity, string};
+	///
+	/// type Short = String<0x8>;
+	/// type Long  = String<0x10>;
+	///
+	/// const CAPACITY: usize = max_capacity(Short::CAPACITY, Long::CAPACITY);
+	///
+	/// let s: String<CAPACITY> = string!("conststr");
+	///
+	/// assert_eq!(String::<0x8>::CAPACITY, 0x8);
+	/// assert_eq!(CAPACITY, 0x10);
+	/// ```
+	pub const CAPACITY: usize = N;
+
 	/// Constructs a new, empty string.
 	#[inline]
 	#[must_use]
@@ -109,6 +173,25 @@ impl<const N: usize> String<N> {
 		Ok(this)
 	}
 
+	/// Constructs a new, constant string, truncating if necessary.
+	///
+	/// If `s` cannot be contained within `N` octets, then only the longest prefix that can is copied.
+	/// Characters are never split, meaning that the resulting string may be shorter than `N` octets even when truncated.
+	#[inline]
+	#[must_use]
+	pub const fn from_str_truncating(s: &str) -> Self {
+		let mut mid = if s.len() < N { s.len() } else { N };
+
+		while !s.is_char_boundary(mid) {
+			mid -= 0x1;
+		}
+
+		let (s, _) = s.split_at(mid);
+
+		// SAFETY: `s` is at most `N` octets long.
+		unsafe { Self::from_str_unchecked(s) }
+	}
+
 	/// Unsafely constructs a new, constant string.
 	///
 	/// See also [`from_str`](Self::from_str) for a safe alternative to this constructor.
@@ -166,6 +249,60 @@ impl<const N: usize> String<N> {
 		Ok(this)
 	}
 
+	/// Constructs a new string from null-padded UTF-8 octets.
+	///
+	/// Contrary to [`from_utf8`](Self::from_utf8), trailing null octets are not considered a part of the string.
+	/// This is the inverse of converting a string into a null-padded array using [`From`], meaning that strings without trailing null characters round-trip.
+	///
+	/// # Errors
+	///
+	/// Each byte value before the padding must be a valid UTF-8 code point.
+	/// If an invalid sequence is found, then this function will return an error.
+	///
+	/// # Panics
+	///
+	/// Calling this function where `M` is greater than `N` will result in a compile-time panic.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use conststr::{String, string};
+	///
+	/// let s: String<0x8> = string!("const");
+	///
+	/// let buf: [u8; 0x8] = s.into();
+	/// assert_eq!(buf, *b"const\0\0\0");
+	///
+	/// assert_eq!(String::from_nul_padded(buf), Ok(s));
+	/// ```
+	#[inline]
+	#[track_caller]
+	pub const fn from_nul_padded<const M: usize>(data: [u8; M]) -> Result<Self, Utf8Error> {
+		let mut len = M;
+		while len > 0x0 && data[len - 0x1] == 0x00 {
+			len -= 0x1;
+		}
+
+		let (s, _) = data.split_at(len);
+
+		if let Err(e) = str::from_utf8(s) {
+			let i = e.valid_up_to();
+			let c = data[i];
+
+			return Err(Utf8Error { value: c, index: i });
+		}
+
+		// SAFETY: The first `len` octets have been tested
+		// to be valid UTF-8, and the remaining are null.
+		let mut this = unsafe { Self::from_utf8_unchecked(data) };
+
+		// SAFETY: `len` is at most `M`, which is at most
+		// `N`. The first `len` octets are valid UTF-8.
+		unsafe { this.set_len(len) };
+
+		Ok(this)
+	}
+
 	/// Unsafely constructs a new string from UTF-8 octets.
 	///
 	/// # Safety
@@ -198,7 +335,15 @@ impl<const N: usize> String<N> {
 			// Reallocate the buffer to `N` elements.
 
 			let mut buf = [0x00; N];
-			buf.copy_from_slice(&data);
+
+			{
+				let src = data.as_ptr();
+				let dst = buf.as_mut_ptr();
+
+				// SAFETY: We have tested that `M` is not
+				// greater than `N`.
+				unsafe { copy_nonoverlapping(src, dst, M) };
+			}
 
 			buf
 		};
@@ -208,6 +353,115 @@ impl<const N: usize> String<N> {
 		unsafe { Self::from_raw_parts(buf, len) }
 	}
 
+	/// Constructs a new string from an iterator of characters.
+	///
+	/// This is in contrast to the [`FromIterator`] implementation, which silently discards all characters that do not fit.
+	///
+	/// # Errors
+	///
+	/// If a character cannot be contained within the string, then an error is returned immediately.
+	#[inline]
+	pub fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, LengthError> {
+		let mut this = Self::new();
+
+		for c in iter {
+			this.push(c)?;
+		}
+
+		Ok(this)
+	}
+
+	/// Constructs a new string from a single character.
+	///
+	/// # Errors
+	///
+	/// If the UTF-8 encoding of `c` cannot be contained within the string, then an error is returned.
+	#[inline]
+	pub const fn from_char(c: char) -> Result<Self, LengthError> {
+		let mut buf = [0x00; 0x4];
+		let s = c.encode_utf8(&mut buf);
+
+		Self::from_str(s)
+	}
+
+	/// Constructs a new string by repeating a single character.
+	///
+	/// # Errors
+	///
+	/// If `count` copies of `c` cannot be contained within the string, then an error is returned.
+	#[inline]
+	pub const fn repeat_char(c: char, count: usize) -> Result<Self, LengthError> {
+		let mut this = Self::new();
+
+		if let Err(e) = this.extend_repeat(c, count) {
+			return Err(e);
+		}
+
+		Ok(this)
+	}
+
+	/// Constructs a new string from a generator function.
+	///
+	/// The function `f` is called with an increasing index (starting at zero), and each returned character is pushed into the string.
+	/// Construction stops when `f` returns `None`.
+	///
+	/// # Errors
+	///
+	/// If a returned character cannot be contained within the string, then an error is returned immediately.
+	#[inline]
+	pub fn from_char_fn<F: FnMut(usize) -> Option<char>>(mut f: F) -> Result<Self, LengthError> {
+		let mut this = Self::new();
+
+		let mut i = 0x0;
+		while let Some(c) = f(i) {
+			this.push(c)?;
+			i += 0x1;
+		}
+
+		Ok(this)
+	}
+
+	/// Constructs a new string from a signed integer.
+	///
+	/// The integer is formatted in decimal, preceded by a minus sign if it is negative.
+	///
+	/// # Errors
+	///
+	/// If the formatted integer cannot be contained within the string, then an error is returned.
+	#[inline]
+	pub const fn from_i64(value: i64) -> Result<Self, LengthError> {
+		// NOTE: The longest possible integer is
+		// `-9223372036854775808`, which is twenty
+		// octets long.
+		let mut buf   = [0x00; 0x14];
+		let mut start = buf.len();
+
+		let mut rest = value.unsigned_abs();
+
+		loop {
+			start -= 0x1;
+			buf[start] = b'0' + (rest % 0xA) as u8;
+
+			rest /= 0xA;
+
+			if rest == 0x0 {
+				break;
+			}
+		}
+
+		if value < 0x0 {
+			start -= 0x1;
+			buf[start] = b'-';
+		}
+
+		let (_, s) = buf.split_at(start);
+
+		// SAFETY: We have only written ASCII characters.
+		let s = unsafe { str::from_utf8_unchecked(s) };
+
+		Self::from_str(s)
+	}
+
 	/// Constructs a constant string from raw parts.
 	///
 	/// The provided parts are not tested in any way.
@@ -224,7 +478,10 @@ impl<const N: usize> String<N> {
 	pub const unsafe fn from_raw_parts(buf: [u8; N], len: usize) -> Self {
 		debug_assert!(len <= N, "cannot construct string that is longer than its capacity");
 
-		Self { len, buf }
+		let this = Self { len, buf };
+		this.debug_assert_invariants();
+
+		this
 	}
 
 	/// Pushes a character into the string.
@@ -238,6 +495,21 @@ impl<const N: usize> String<N> {
 		self.insert(index, c)
 	}
 
+	/// Pushes a character into the string, returning it on failure.
+	///
+	/// This is equivalent to [`push`](Self::push), except that the character is handed back if it does not fit.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided character, then the character is returned and the string is left unmodified.
+	#[inline]
+	pub const fn checked_push(&mut self, c: char) -> Result<(), char> {
+		match self.push(c) {
+			Ok(())  => Ok(()),
+			Err(..) => Err(c),
+		}
+	}
+
 	/// Pushes a string into the string.
 	///
 	/// # Errors
@@ -245,8 +517,247 @@ impl<const N: usize> String<N> {
 	/// If the string cannot contain the provided, other string, then an error will be returned.
 	#[inline(always)]
 	pub const fn push_str(&mut self, s: &str) -> Result<(), LengthError> {
-		let index = self.len();
-		self.insert_str(index, s)
+		self.append_str(s)
+	}
+
+	/// Appends a string slice to the end of the string.
+	///
+	/// This is equivalent to [`insert_str`](Self::insert_str) at the string's length, but skips the boundary check and the sift, neither of which are needed when appending.
+	#[inline(always)]
+	const fn append_str(&mut self, s: &str) -> Result<(), LengthError> {
+		let s_len     = s.len();
+		let old_len   = self.len();
+		let remaining = N - old_len;
+
+		if s_len > remaining {
+			return Err(LengthError {
+				remaining,
+				count: s_len,
+			});
+		}
+
+		// SAFETY: We have checked that the string can
+		// contain `s`. The two cannot overlap as `self` is
+		// borrowed mutably.
+		unsafe {
+			let src = s.as_ptr();
+			let dst = self.as_mut_ptr().add(old_len);
+
+			copy_nonoverlapping(src, dst, s_len);
+		}
+
+		self.len = old_len + s_len;
+		self.debug_assert_invariants();
+
+		Ok(())
+	}
+
+	/// Pushes a character to the front of the string.
+	///
+	/// This is equivalent to calling [`insert`](Self::insert) with an index of zero.
+	/// Note that all existing octets have to be shifted, making this an *O*(*n*) operation.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided character, then an error will be returned.
+	#[inline]
+	pub const fn push_front(&mut self, c: char) -> Result<(), LengthError> {
+		self.insert(0x0, c)
+	}
+
+	/// Pushes a string slice to the front of the string.
+	///
+	/// This is equivalent to calling [`insert_str`](Self::insert_str) with an index of zero.
+	/// Note that all existing octets have to be shifted, making this an *O*(*n*) operation.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided string slice, then an error will be returned.
+	#[inline]
+	pub const fn push_str_front(&mut self, s: &str) -> Result<(), LengthError> {
+		self.insert_str(0x0, s)
+	}
+
+	/// Pushes a character into the string multiple times.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain `count` copies of `c`, then an error is returned and the string is left unchanged.
+	#[inline]
+	pub const fn extend_repeat(&mut self, c: char, count: usize) -> Result<(), LengthError> {
+		let mut char_buf = [0x00; 0x4];
+		let char_buf = c.encode_utf8(&mut char_buf).as_bytes();
+
+		let char_len = char_buf.len();
+
+		let old_len = self.len();
+		let add_len = count.saturating_mul(char_len);
+
+		if add_len > N - old_len {
+			return Err(LengthError {
+				remaining: N - old_len,
+				count:     add_len,
+			});
+		}
+
+		let mut i = 0x0;
+		while i < add_len {
+			self.buf[old_len + i] = char_buf[i % char_len];
+			i += 0x1;
+		}
+
+		self.len = old_len + add_len;
+		self.debug_assert_invariants();
+
+		Ok(())
+	}
+
+	/// Pushes a character into the string, returning the string by value.
+	///
+	/// This allows for chaining pushes when constructing strings:
+	///
+	/// ```rust
+	/// use conststr::String;
+	///
+	/// let s = String::<0x10>::new()
+	///     .try_push('a')
+	///     .and_then(|s| s.try_push_str("bc"))
+	///     .unwrap();
+	///
+	/// assert_eq!(s, "abc");
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided character, then the unmodified string is returned alongside an error.
+	#[inline]
+	pub const fn try_push(mut self, c: char) -> Result<Self, (Self, LengthError)> {
+		match self.push(c) {
+			Ok(()) => Ok(self),
+			Err(e) => Err((self, e)),
+		}
+	}
+
+	/// Pushes a string into the string, returning the string by value.
+	///
+	/// See [`try_push`](Self::try_push) for more information.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided, other string, then the unmodified string is returned alongside an error.
+	#[inline]
+	pub const fn try_push_str(mut self, s: &str) -> Result<Self, (Self, LengthError)> {
+		match self.push_str(s) {
+			Ok(()) => Ok(self),
+			Err(e) => Err((self, e)),
+		}
+	}
+
+	/// Pushes a token into a separated list, unless it is already present.
+	///
+	/// The string is treated as a list of tokens delimited by `sep`.
+	/// If `item` is not already one of these tokens, then it is pushed (preceded by `sep` if the string is not empty) and `true` is returned.
+	/// Otherwise, the string is left unmodified and `false` is returned.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the separator and token, then an error will be returned.
+	/// In this case, the string is left unmodified.
+	#[inline]
+	pub fn push_str_if_absent(&mut self, sep: char, item: &str) -> Result<bool, LengthError> {
+		if !self.is_empty() && self.split(sep).any(|token| token == item) {
+			return Ok(false);
+		}
+
+		let mut this = *self;
+
+		if !this.is_empty() {
+			this.push(sep)?;
+		}
+
+		this.push_str(item)?;
+
+		*self = this;
+		Ok(true)
+	}
+
+	/// Pushes a floating-point number with a fixed amount of decimals.
+	///
+	/// The number is rounded half-up to exactly `decimals` fractional digits.
+	/// If `decimals` is zero, then no decimal point is pushed.
+	/// Only the first nineteen fractional digits are computed, and any further digits are zero.
+	///
+	/// Infinities are pushed as `inf` and `-inf`, and NaNs are pushed as `NaN`.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the formatted number, then an error will be returned.
+	/// In this case, the string is left unmodified.
+	#[inline]
+	pub fn push_f32_fixed(&mut self, value: f32, decimals: u8) -> Result<(), LengthError> {
+		const MAX_DIGITS: u8 = 0x13;
+
+		fn push_digits<const N: usize>(s: &mut String<N>, mut value: u128, width: usize) -> Result<(), LengthError> {
+			let mut buf = [0x00; 0x27];
+			let mut len = 0x0;
+
+			while value != 0x0 || len < width {
+				len += 0x1;
+				buf[buf.len() - len] = b'0' + (value % 0xA) as u8;
+
+				value /= 0xA;
+			}
+
+			// SAFETY: All pushed octets are ASCII digits.
+			let digits = unsafe { str::from_utf8_unchecked(&buf[buf.len() - len..]) };
+			s.push_str(digits)
+		}
+
+		let value = f64::from(value);
+
+		if value.is_nan() {
+			return self.push_str("NaN");
+		}
+
+		let mut this = *self;
+
+		if value.is_sign_negative() {
+			this.push('-')?;
+		}
+
+		let value = value.abs();
+
+		if value.is_infinite() {
+			this.push_str("inf")?;
+		} else {
+			let digits = decimals.min(MAX_DIGITS);
+			let scale  = 0xAu64.pow(u32::from(digits));
+
+			let mut whole    = value as u128;
+			let mut fraction = ((value - whole as f64) * scale as f64 + 0.5) as u64;
+
+			if fraction >= scale {
+				whole    += 0x1;
+				fraction -= scale;
+			}
+
+			push_digits(&mut this, whole, 0x1)?;
+
+			if decimals != 0x0 {
+				this.push('.')?;
+
+				if digits != 0x0 {
+					push_digits(&mut this, u128::from(fraction), usize::from(digits))?;
+				}
+
+				for _ in digits..decimals {
+					this.push('0')?;
+				}
+			}
+		}
+
+		*self = this;
+		Ok(())
 	}
 
 	/// Inserts a character into the string.
@@ -328,11 +839,376 @@ impl<const N: usize> String<N> {
 		// Update the length counter and return.
 
 		self.len = new_len;
+		self.debug_assert_invariants();
 
 		Ok(())
 	}
 
-	/// Pops the next character from the string.
+	/// Inserts as much of a string as possible, returning the remainder on failure.
+	///
+	/// Contrary to [`insert_str`](Self::insert_str), the longest prefix of `s` that fits is inserted even if the entirety of `s` does not.
+	/// The prefix is always cut on a character boundary, so a character is never split.
+	///
+	/// This allows for filling a buffer before spilling the remaining characters into the next.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the entirety of `s`, then the suffix that was not inserted is returned.
+	///
+	/// # Panics
+	///
+	/// If `index` is not on a character boundary (including if it is out of bounds), then this method will panic.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use conststr::{String, string};
+	///
+	/// let mut s: String<0x6> = string!("ab");
+	///
+	/// assert_eq!(s.insert_str_checked(0x1, "cdefg"), Err("g"));
+	/// assert_eq!(s, "acdefb");
+	/// ```
+	#[inline]
+	#[track_caller]
+	pub const fn insert_str_checked<'a>(&mut self, index: usize, s: &'a str) -> Result<(), &'a str> {
+		// Find the longest prefix that fits. Note that we
+		// cannot split characters.
+
+		let remaining = N - self.len();
+
+		let mut mid = if s.len() < remaining { s.len() } else { remaining };
+		while !s.is_char_boundary(mid) {
+			mid -= 0x1;
+		}
+
+		let (head, tail) = s.split_at(mid);
+
+		if self.insert_str(index, head).is_err() {
+			unreachable!();
+		}
+
+		if tail.is_empty() {
+			Ok(())
+		} else {
+			Err(tail)
+		}
+	}
+
+	/// Inserts a character into the string at a character index.
+	///
+	/// Contrary to [`insert`](Self::insert), `char_index` denotes the amount of characters -- and not octets -- preceding the insertion point.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided character, then an error will be returned.
+	///
+	/// # Panics
+	///
+	/// If `char_index` is greater than the amount of characters in the string, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub const fn insert_char_at(&mut self, char_index: usize, c: char) -> Result<(), LengthError> {
+		let index = self.char_boundary_at(char_index);
+		self.insert(index, c)
+	}
+
+	/// Inserts a string into the string at a character index.
+	///
+	/// Contrary to [`insert_str`](Self::insert_str), `char_index` denotes the amount of characters -- and not octets -- preceding the insertion point.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the provided, other string, then an error will be returned.
+	///
+	/// # Panics
+	///
+	/// If `char_index` is greater than the amount of characters in the string, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub const fn insert_str_at(&mut self, char_index: usize, s: &str) -> Result<(), LengthError> {
+		let index = self.char_boundary_at(char_index);
+		self.insert_str(index, s)
+	}
+
+	/// Replaces a range of the string with another string.
+	///
+	/// The replacement may be longer or shorter than the range, in which case the following octets are shifted accordingly.
+	///
+	/// # Errors
+	///
+	/// If the string cannot contain the replacement (after having removed the range), then an error is returned.
+	/// In this case, the string is left unmodified.
+	///
+	/// # Panics
+	///
+	/// If either end of `range` is not on a character boundary (including if it is out of bounds), or if the range starts after it ends, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, replacement: &str) -> Result<(), LengthError> {
+		let len = self.len();
+
+		let (start, end) = self.resolve_range(range);
+
+		// Check that we can contain the replacement.
+
+		let kept    = len - (end - start);
+		let new_len = kept.checked_add(replacement.len()).unwrap();
+
+		if new_len > N {
+			return Err(LengthError {
+				remaining: N - kept,
+				count:     replacement.len(),
+			});
+		}
+
+		// Shift the tail to its new position. This may
+		// move it in either direction.
+
+		let mid = start + replacement.len();
+
+		self.buf.copy_within(end..len, mid);
+		self.buf[start..mid].copy_from_slice(replacement.as_bytes());
+
+		self.len = new_len;
+		self.debug_assert_invariants();
+
+		Ok(())
+	}
+
+	/// Keeps only a range of the string.
+	///
+	/// The octets in `range` are shifted to the front of the string, and everything else is removed.
+	/// This is equivalent to replacing the string with [`substr`](Self::substr), except that it happens in place.
+	///
+	/// # Panics
+	///
+	/// If either end of `range` is not on a character boundary (including if it is out of bounds), or if the range starts after it ends, then this method will panic.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use conststr::{String, string};
+	///
+	/// let mut s: String<0x10> = string!("conststr");
+	///
+	/// s.keep_range(0x5..);
+	/// assert_eq!(s, "str");
+	/// ```
+	#[inline]
+	#[track_caller]
+	pub fn keep_range<R: RangeBounds<usize>>(&mut self, range: R) {
+		let (start, end) = self.resolve_range(range);
+
+		self.buf.copy_within(start..end, 0x0);
+
+		self.len = end - start;
+		self.debug_assert_invariants();
+	}
+
+	/// Converts a range of octet indices into its start and end.
+	///
+	/// # Panics
+	///
+	/// If either end of `range` is not on a character boundary (including if it is out of bounds), or if the range starts after it ends, then this method will panic.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	fn resolve_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+		let start = match range.start_bound() {
+			Bound::Included(&index) => index,
+			Bound::Excluded(&index) => index.checked_add(0x1).unwrap(),
+			Bound::Unbounded        => 0x0,
+		};
+
+		let end = match range.end_bound() {
+			Bound::Included(&index) => index.checked_add(0x1).unwrap(),
+			Bound::Excluded(&index) => index,
+			Bound::Unbounded        => self.len(),
+		};
+
+		assert!(start <= end, "cannot use range that starts after it ends");
+
+		assert!(
+			self.is_char_boundary(start) && self.is_char_boundary(end),
+			"cannot use range outside of character boundaries",
+		);
+
+		(start, end)
+	}
+
+	/// Swaps two characters in the string.
+	///
+	/// Both indices denote the amount of characters -- and not octets -- preceding the respective characters.
+	/// If the two characters have different lengths, then the octets between them are shifted accordingly.
+	///
+	/// # Panics
+	///
+	/// If either index is not less than the amount of characters in the string, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub fn swap_chars(&mut self, a_char_index: usize, b_char_index: usize) {
+		let (a_char_index, b_char_index) = if a_char_index <= b_char_index {
+			(a_char_index, b_char_index)
+		} else {
+			(b_char_index, a_char_index)
+		};
+
+		let len = self.len();
+
+		let a_start = self.char_boundary_at(a_char_index);
+		let b_start = self.char_boundary_at(b_char_index);
+
+		assert!(b_start < len, "cannot swap character past string");
+
+		if a_start == b_start {
+			return;
+		}
+
+		let a_len = utf8_char_len(self.buf[a_start]);
+		let b_len = utf8_char_len(self.buf[b_start]);
+
+		let b_end = b_start + b_len;
+
+		// Rotate `a mid b` into `mid b a`, and then the
+		// first part into `b mid a`.
+
+		let region = &mut self.buf[a_start..b_end];
+
+		region.rotate_left(a_len);
+
+		let (head, _) = region.split_at_mut(b_end - a_start - a_len);
+		head.rotate_right(b_len);
+
+		self.debug_assert_invariants();
+	}
+
+	/// Collapses consecutive runs of a character into a single occurrence.
+	///
+	/// See [`dedup_by`](Self::dedup_by) for a more general version.
+	#[inline]
+	pub fn dedup_char(&mut self, c: char) {
+		self.dedup_by(|a, b| a == c && b == c);
+	}
+
+	/// Removes consecutive characters that are considered equal by a predicate.
+	///
+	/// The predicate `f` is called with each character and the last character that was kept before it, in that order.
+	/// If it returns `true`, then the former character is removed.
+	/// This mirrors `Vec::dedup_by`.
+	///
+	/// The string is compacted in place.
+	#[inline]
+	pub fn dedup_by<F: FnMut(char, char) -> bool>(&mut self, mut f: F) {
+		let len = self.len();
+
+		let mut read  = 0x0;
+		let mut write = 0x0;
+		let mut prev  = None;
+
+		while read < len {
+			// SAFETY: Octets at or after `read` have not been
+			// overwritten and `read` is always on a boundary.
+			let tail = unsafe { str::from_utf8_unchecked(&self.buf[read..len]) };
+
+			let Some(c) = tail.chars().next() else {
+				unreachable!();
+			};
+
+			let c_len = c.len_utf8();
+
+			let is_dup = prev.is_some_and(|prev| f(c, prev));
+
+			if !is_dup {
+				self.buf.copy_within(read..read + c_len, write);
+
+				write += c_len;
+				prev   = Some(c);
+			}
+
+			read += c_len;
+		}
+
+		self.len = write;
+		self.debug_assert_invariants();
+	}
+
+	/// Pads the start of the string with a character.
+	///
+	/// Copies of `fill` are prepended until the string is `target_len` octets long.
+	/// If `fill` is longer than one octet, then padding stops short of `target_len` instead of splitting a character, meaning that the string may end up being up to three octets shorter than requested.
+	///
+	/// Strings that are already at least `target_len` octets long are left unchanged.
+	///
+	/// # Errors
+	///
+	/// If `target_len` is greater than `N`, then an error is returned and the string is left unchanged.
+	#[inline]
+	pub fn pad_start(&mut self, target_len: usize, fill: char) -> Result<(), LengthError> {
+		let len = self.len();
+
+		let pad_len = self.pad_len(target_len, fill)?;
+
+		self.buf.copy_within(0x0..len, pad_len);
+
+		for chunk in self.buf[..pad_len].chunks_exact_mut(fill.len_utf8()) {
+			fill.encode_utf8(chunk);
+		}
+
+		self.len = len + pad_len;
+		self.debug_assert_invariants();
+
+		Ok(())
+	}
+
+	/// Pads the end of the string with a character.
+	///
+	/// Copies of `fill` are appended until the string is `target_len` octets long.
+	/// See [`pad_start`](Self::pad_start) for how multi-octet characters are handled.
+	///
+	/// # Errors
+	///
+	/// If `target_len` is greater than `N`, then an error is returned and the string is left unchanged.
+	#[inline]
+	pub fn pad_end(&mut self, target_len: usize, fill: char) -> Result<(), LengthError> {
+		let len = self.len();
+
+		let pad_len = self.pad_len(target_len, fill)?;
+
+		for chunk in self.buf[len..len + pad_len].chunks_exact_mut(fill.len_utf8()) {
+			fill.encode_utf8(chunk);
+		}
+
+		self.len = len + pad_len;
+		self.debug_assert_invariants();
+
+		Ok(())
+	}
+
+	/// Computes the amount of octets to pad with.
+	///
+	/// The result is always a multiple of the length of `fill`.
+	#[inline]
+	const fn pad_len(&self, target_len: usize, fill: char) -> Result<usize, LengthError> {
+		let len = self.len();
+
+		if target_len > N {
+			return Err(LengthError {
+				remaining: N - len,
+				count:     target_len.saturating_sub(len),
+			});
+		}
+
+		let Some(missing) = target_len.checked_sub(len) else {
+			return Ok(0x0);
+		};
+
+		let fill_len = fill.len_utf8();
+
+		Ok(missing - missing % fill_len)
+	}
+
+	/// Pops the next character from the string.
 	#[inline]
 	pub const fn pop(&mut self) -> Option<char> {
 		if self.is_empty() {
@@ -345,6 +1221,19 @@ impl<const N: usize> String<N> {
 		Some(c)
 	}
 
+	/// Pops the first character from the string.
+	///
+	/// The remaining characters are moved to the front of the string.
+	#[inline]
+	pub const fn pop_front(&mut self) -> Option<char> {
+		if self.is_empty() {
+			return None;
+		}
+
+		let c = self.remove(0x0);
+		Some(c)
+	}
+
 	/// Removes the character at the specificed index.
 	///
 	/// # Panics
@@ -394,12 +1283,56 @@ impl<const N: usize> String<N> {
 		// Update the length counter.
 
 		self.len = new_len;
+		self.debug_assert_invariants();
 
 		// Return the character.
 
 		c
 	}
 
+	/// Removes the character at the specificed index, if it is valid.
+	///
+	/// Contrary to [`remove`](Self::remove), this method returns `None` (leaving the string unmodified) instead of panicking if `index` is not at the boundary of a character or if it is at the very end of the string.
+	#[inline]
+	pub const fn remove_checked(&mut self, index: usize) -> Option<char> {
+		if index >= self.len() || !self.is_char_boundary(index) {
+			return None;
+		}
+
+		let c = self.remove(index);
+		Some(c)
+	}
+
+	/// Removes a prefix from the string.
+	///
+	/// If the string starts with `prefix`, then the prefix is removed and `true` is returned.
+	/// Otherwise, the string is left unmodified and `false` is returned.
+	#[inline]
+	pub const fn consume_prefix(&mut self, prefix: &str) -> bool {
+		if !self.matches_at(0x0, prefix) {
+			return false;
+		}
+
+		let old_len = self.len();
+		let new_len = old_len - prefix.len();
+
+		// Sift the remaining octets to the front.
+
+		{
+			let base: *mut u8 = self.as_mut_ptr();
+
+			let src: *const u8 = unsafe { base.add(prefix.len()) };
+			let dst: *mut   u8 = base;
+
+			unsafe { copy(src, dst, new_len) };
+		}
+
+		self.len = new_len;
+		self.debug_assert_invariants();
+
+		true
+	}
+
 	/// Truncates the string to the specified length.
 	///
 	/// # Panics
@@ -413,6 +1346,56 @@ impl<const N: usize> String<N> {
 		);
 
 		self.len = len;
+		self.debug_assert_invariants();
+	}
+
+	/// Truncates the string to the specified amount of characters.
+	///
+	/// If the string contains fewer than `char_count` characters, then this method does nothing.
+	#[inline]
+	pub const fn truncate_chars(&mut self, char_count: usize) {
+		let buf = self.as_bytes();
+
+		let mut len   = 0x0;
+		let mut count = 0x0;
+
+		while len < buf.len() && count < char_count {
+			len   += utf8_char_len(buf[len]);
+			count += 0x1;
+		}
+
+		// SAFETY: `len` is always advanced by whole char-
+		// acters.
+		self.len = len;
+		self.debug_assert_invariants();
+	}
+
+	/// Splits the string into two at the given index.
+	///
+	/// The string is truncated to `at` octets, and the removed octets are returned as a new string.
+	///
+	/// # Panics
+	///
+	/// If `at` is not on a character boundary (including if it is out of bounds), then this method will panic.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub const fn split_off(&mut self, at: usize) -> Self {
+		assert!(
+			self.is_char_boundary(at),
+			"cannot split string at non-character boundary",
+		);
+
+		let (_, tail) = self.split_at(at);
+
+		// SAFETY: `tail` is a part of `self` and can there-
+		// fore not be longer than `N`.
+		let other = unsafe { Self::from_str_unchecked(tail) };
+
+		self.len = at;
+		self.debug_assert_invariants();
+
+		other
 	}
 
 	/// Completely clears the string.
@@ -441,108 +1424,1742 @@ impl<const N: usize> String<N> {
 		self.as_mut_str().make_ascii_lowercase();
 	}
 
+	/// Converts all ASCII words to title case.
+	///
+	/// Words are delimited by ASCII whitespace.
+	/// The first character of each word is converted to uppercase (if ASCII), and all other ASCII characters are converted to lowercase.
+	/// Non-ASCII octets are ignored.
+	#[inline]
+	pub const fn make_ascii_titlecase(&mut self) {
+		let len = self.len();
+
+		// SAFETY: We only change the case of ASCII char-
+		// acters, which cannot invalidate any UTF-8 se-
+		// quences.
+		let buf = unsafe { self.as_bytes_mut() };
+
+		let mut at_start = true;
+
+		let mut i = 0x0;
+		while i < len {
+			let octet = buf[i];
+
+			if octet.is_ascii_whitespace() {
+				at_start = true;
+			} else {
+				buf[i] = if at_start {
+					octet.to_ascii_uppercase()
+				} else {
+					octet.to_ascii_lowercase()
+				};
+
+				at_start = false;
+			}
+
+			i += 0x1;
+		}
+	}
+
+	/// Returns a copy of the string with all ASCII words converted to title case.
+	///
+	/// See [`make_ascii_titlecase`](Self::make_ascii_titlecase) for more information.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	#[must_use]
+	pub const fn to_ascii_titlecase(&self) -> Self {
+		let mut s = *self;
+		s.make_ascii_titlecase();
+
+		s
+	}
+
+	/// Returns a copy of the string with all characters converted to uppercase.
+	///
+	/// Contrary to [`make_ascii_uppercase`](Self::make_ascii_uppercase), this uses the full Unicode case mapping of [`char::to_uppercase`].
+	/// Some characters map to multiple characters (e.g. `ß` maps to `SS`), meaning that the converted string may be longer than `self`.
+	///
+	/// # Errors
+	///
+	/// If the converted string cannot be contained in `M` octets, then an error is returned.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	pub fn to_uppercase<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let mut s = String::new();
+
+		for c in self.chars().flat_map(char::to_uppercase) {
+			s.push(c)?;
+		}
+
+		Ok(s)
+	}
+
+	/// Returns a copy of the string with all characters converted to lowercase.
+	///
+	/// Contrary to [`make_ascii_lowercase`](Self::make_ascii_lowercase), this uses the full Unicode case mapping of [`char::to_lowercase`].
+	/// As each character is mapped on its own, context-dependent mappings (such as the final form of the Greek sigma) are not applied.
+	///
+	/// # Errors
+	///
+	/// If the converted string cannot be contained in `M` octets, then an error is returned.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	pub fn to_lowercase<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let mut s = String::new();
+
+		for c in self.chars().flat_map(char::to_lowercase) {
+			s.push(c)?;
+		}
+
+		Ok(s)
+	}
+
+	/// Normalises all ASCII whitespace in the string.
+	///
+	/// Leading and trailing whitespace is removed, and all internal runs of whitespace are collapsed into a single space.
+	/// Non-ASCII whitespace is left untouched.
+	#[inline]
+	pub const fn normalize_spaces(&mut self) {
+		let len = self.len();
+
+		// SAFETY: We only remove ASCII whitespace or re-
+		// place it with spaces, which cannot invalidate
+		// any UTF-8 sequences.
+		let buf = unsafe { self.as_bytes_mut() };
+
+		let mut read    = 0x0;
+		let mut write   = 0x0;
+		let mut pending = false;
+
+		while read < len {
+			let octet = buf[read];
+
+			if octet.is_ascii_whitespace() {
+				// Leading whitespace is dropped entirely.
+				pending = write != 0x0;
+			} else {
+				if pending {
+					buf[write] = b' ';
+					write += 0x1;
+
+					pending = false;
+				}
+
+				buf[write] = octet;
+				write += 0x1;
+			}
+
+			read += 0x1;
+		}
+
+		self.len = write;
+		self.debug_assert_invariants();
+	}
+
+	/// Expands all tabs into spaces.
+	///
+	/// Each tab is replaced with enough spaces to reach the next column that is a multiple of `tab_width`.
+	/// Columns are counted in characters and are reset at each line feed.
+	///
+	/// # Errors
+	///
+	/// If the expanded string cannot be contained in `M` octets, then an error is returned.
+	///
+	/// # Panics
+	///
+	/// If `tab_width` is zero, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub fn expand_tabs<const M: usize>(&self, tab_width: usize) -> Result<String<M>, LengthError> {
+		assert!(tab_width != 0x0, "cannot expand tabs with a width of zero");
+
+		let mut s   = String::new();
+		let mut col = 0x0;
+
+		for c in self.chars() {
+			match c {
+				'\t' => {
+					let count = tab_width - col % tab_width;
+
+					for _ in 0x0..count {
+						s.push(' ')?;
+					}
+
+					col += count;
+				}
+
+				'\n' => {
+					s.push(c)?;
+					col = 0x0;
+				}
+
+				_ => {
+					s.push(c)?;
+					col += 0x1;
+				}
+			}
+		}
+
+		Ok(s)
+	}
+
+	/// Converts the string to ASCII, replacing all non-ASCII characters with `?`.
+	///
+	/// If the converted string cannot be contained in `M` octets, then it is truncated.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	#[must_use]
+	pub fn to_ascii_lossy<const M: usize>(&self) -> String<M> {
+		let mut s = String::new();
+
+		for c in self.chars() {
+			let c = if c.is_ascii() { c } else { '?' };
+
+			if s.push(c).is_err() {
+				break;
+			}
+		}
+
+		s
+	}
+
+	/// Escapes the string for use as a single word in a POSIX shell.
+	///
+	/// Strings consisting only of ASCII alphanumerics and the symbols `%+,-./:=@_` are returned unquoted.
+	/// All other strings -- including the empty string -- are enclosed in single quotes, with each embedded single quote replaced by `'\''`.
+	///
+	/// # Errors
+	///
+	/// If the escaped string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub fn shell_escape<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let is_plain = !self.is_empty() && self.bytes().all(|octet| {
+			matches!(octet, b'%' | b'+' | b',' | b'-' | b'.' | b'/' | b':' | b'=' | b'@' | b'_')
+				|| octet.is_ascii_alphanumeric()
+		});
+
+		if is_plain {
+			return String::from_str(self);
+		}
+
+		let mut s = String::new();
+
+		s.push('\'')?;
+
+		for c in self.chars() {
+			if c == '\'' {
+				s.push_str("'\\''")?;
+			} else {
+				s.push(c)?;
+			}
+		}
+
+		s.push('\'')?;
+
+		Ok(s)
+	}
+
+	/// Escapes the string using [`char::escape_default`].
+	///
+	/// # Errors
+	///
+	/// If the escaped string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub fn escape_default<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		String::try_from_iter(self.as_str().escape_default())
+	}
+
+	/// Escapes the string using [`char::escape_debug`].
+	///
+	/// # Errors
+	///
+	/// If the escaped string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub fn escape_debug<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		String::try_from_iter(self.as_str().escape_debug())
+	}
+
+	/// Quotes the string for use as a single CSV field.
+	///
+	/// Strings not containing any commas, double quotes, or line breaks are returned unquoted.
+	/// All other strings are enclosed in double quotes, with each embedded double quote doubled.
+	///
+	/// # Errors
+	///
+	/// If the quoted string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub fn csv_field<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let is_plain = !self.bytes().any(|octet| matches!(octet, b'\n' | b'\r' | b'"' | b','));
+
+		if is_plain {
+			return String::from_str(self);
+		}
+
+		let mut s = String::new();
+
+		s.push('"')?;
+
+		for c in self.chars() {
+			if c == '"' {
+				s.push_str("\"\"")?;
+			} else {
+				s.push(c)?;
+			}
+		}
+
+		s.push('"')?;
+
+		Ok(s)
+	}
+
+	/// Converts the string to a canonical hostname.
+	///
+	/// The string is converted to ASCII lowercase, and a single trailing dot (denoting the root label) is removed.
+	/// No other validation is done.
+	///
+	/// # Errors
+	///
+	/// If the canonical hostname cannot be contained in `M` octets, then an error is returned.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	pub fn to_canonical_host<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let host = self.strip_suffix_str(".").unwrap_or(self);
+
+		let mut s = String::from_str(host)?;
+		s.make_ascii_lowercase();
+
+		Ok(s)
+	}
+
+	/// Repeats the string `K` times.
+	///
+	/// The resulting capacity `M` must be able to contain `K` full copies of this string, i.e. `M` must not be less than `N * K`.
+	/// This makes the operation infallible.
+	///
+	/// # Panics
+	///
+	/// If `M` is less than `N * K`, then compilation will fail.
+	#[inline]
+	#[must_use]
+	pub const fn repeat<const K: usize, const M: usize>(&self) -> String<M> {
+		const {
+			assert!(
+				matches!(N.checked_mul(K), Some(len) if len <= M),
+				"cannot repeat string into smaller buffer",
+			);
+		};
+
+		let len = self.len();
+
+		let mut buf = [0x00; M];
+
+		let mut i = 0x0;
+		while i < K {
+			// SAFETY: `buf` can contain `K` copies of up to
+			// `N` octets each.
+			unsafe {
+				let src = self.as_ptr();
+				let dst = buf.as_mut_ptr().add(i * len);
+
+				copy_nonoverlapping(src, dst, len);
+			}
+
+			i += 0x1;
+		}
+
+		// SAFETY: The buffer is only filled with copies of
+		// a valid string.
+		unsafe { String::from_raw_parts(buf, len * K) }
+	}
+
+	/// Reverses the order of the string's dot-separated labels.
+	///
+	/// For example, `"a.b.c"` is converted to `"c.b.a"`.
+	/// Empty labels are preserved, and a string without dots is returned unchanged.
+	///
+	/// # Errors
+	///
+	/// If the reversed string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub fn reverse_dotted<const M: usize>(&self) -> Result<String<M>, LengthError> {
+		let mut s = String::new();
+
+		for (i, label) in self.rsplit('.').enumerate() {
+			if i != 0x0 {
+				s.push('.')?;
+			}
+
+			s.push_str(label)?;
+		}
+
+		Ok(s)
+	}
+
+	/// Reverses the order of characters in the string.
+	///
+	/// Note that this reverses Unicode scalar values and not grapheme clusters.
+	/// Combining characters (such as accents) will therefore be moved in front of -- and consequently attach to -- other characters than before.
+	///
+	/// The returned string has the same length as `self`.
+	#[inline]
+	#[must_use]
+	pub const fn reverse(&self) -> Self {
+		let src = self.as_bytes();
+		let len = self.len();
+
+		let mut buf = [0x00; N];
+
+		let mut i = 0x0;
+		while i < len {
+			let (_, c_len) = decode_utf8(self.as_str(), i);
+
+			let dst = len - i - c_len;
+
+			let mut j = 0x0;
+			while j < c_len {
+				buf[dst + j] = src[i + j];
+				j += 0x1;
+			}
+
+			i += c_len;
+		}
+
+		// SAFETY: The characters of `self` have only been
+		// reordered, so the buffer is still valid UTF-8.
+		unsafe { Self::from_raw_parts(buf, len) }
+	}
+
+	/// Joins an array of strings with a separator.
+	///
+	/// The separator is inserted between each pair of adjacent strings, but not before the first or after the last.
+	///
+	/// # Errors
+	///
+	/// If the joined string cannot be contained in `M` octets, then an error is returned.
+	#[inline]
+	pub const fn join_array<const K: usize, const M: usize>(parts: &[Self; K], sep: &str) -> Result<String<M>, LengthError> {
+		let mut s = String::new();
+
+		let mut i = 0x0;
+		while i < K {
+			if i != 0x0 {
+				if let Err(e) = s.push_str(sep) {
+					return Err(e);
+				}
+			}
+
+			if let Err(e) = s.push_str(parts[i].as_str()) {
+				return Err(e);
+			}
+
+			i += 0x1;
+		}
+
+		Ok(s)
+	}
+
+	/// Concatenates an array of strings.
+	///
+	/// This is equivalent to [`join_array`](Self::join_array) with an empty separator.
+	///
+	/// # Errors
+	///
+	/// If the concatenated string cannot be contained in `M` octets, then an error is returned.
+	#[inline(always)]
+	pub const fn concat_array<const K: usize, const M: usize>(parts: &[Self; K]) -> Result<String<M>, LengthError> {
+		Self::join_array(parts, "")
+	}
+
+	/// Iterates over the rolling hashes of all windows in the string.
+	///
+	/// A polynomial hash is yielded for each window of `window` octets, with the base `256` and the (prime) modulus `1000000007`.
+	/// Each hash is derived from the previous one instead of being recomputed, making this suitable for Rabin-Karp searches.
+	///
+	/// If the string is shorter than `window`, then no hashes are yielded.
+	///
+	/// # Panics
+	///
+	/// If `window` is zero, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub fn rolling_hashes(&self, window: usize) -> impl Iterator<Item = u64> + '_ {
+		const BASE:    u64 = 0x100;
+		const MODULUS: u64 = 0x3B9A_CA07;
+
+		let buf = self.as_bytes();
+
+		// Compute the weight of the leading octet so that
+		// it can be removed when the window moves.
+		let mut weight = 0x1;
+		for _ in 0x1..window {
+			weight = weight * BASE % MODULUS;
+		}
+
+		buf.windows(window).enumerate().scan(0x0, move |hash, (i, octets)| {
+			*hash = if i == 0x0 {
+				octets
+					.iter()
+					.fold(0x0, |hash, &octet| (hash * BASE + u64::from(octet)) % MODULUS)
+			} else {
+				let old = u64::from(buf[i - 0x1]) * weight % MODULUS;
+				let new = u64::from(octets[window - 0x1]);
+
+				((*hash + MODULUS - old) * BASE + new) % MODULUS
+			};
+
+			Some(*hash)
+		})
+	}
+
+	/// Gets a hexadecimal representation of the string's octets.
+	///
+	/// The returned object displays each octet as two (uppercase) hexadecimal digits, separated by spaces -- e.g. `"AB"` is displayed as `41 42`.
+	/// Only the octets up to [`len`](Self::len) are displayed.
+	#[inline]
+	#[must_use]
+	pub fn debug_hex(&self) -> impl Display + '_ {
+		struct DebugHex<'a>(&'a [u8]);
+
+		impl Display for DebugHex<'_> {
+			#[inline]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				for (i, octet) in self.0.iter().enumerate() {
+					if i != 0x0 {
+						f.write_str(" ")?;
+					}
+
+					write!(f, "{octet:02X}")?;
+				}
+
+				Ok(())
+			}
+		}
+
+		DebugHex(self.as_bytes())
+	}
+
+	/// Computes the prefix function of the string.
+	///
+	/// The table is computed over *octets* and not characters.
+	/// Each entry `i` denotes the length of the longest proper prefix of `self[..=i]` that is also a suffix of it.
+	/// This is the failure function used by Knuth-Morris-Pratt searches.
+	///
+	/// Entries beyond the string's length are zero.
+	///
+	/// # Panics
+	///
+	/// If `M` is less than `N`, then compilation will fail.
+	#[inline]
+	#[must_use]
+	pub fn prefix_function<const M: usize>(&self) -> [usize; M] {
+		const { assert!(M >= N, "cannot compute prefix function into smaller table") };
+
+		let buf = self.as_bytes();
+
+		let mut table = [0x0; M];
+
+		for i in 0x1..buf.len() {
+			let mut j = table[i - 0x1];
+
+			while j > 0x0 && buf[i] != buf[j] {
+				j = table[j - 0x1];
+			}
+
+			if buf[i] == buf[j] {
+				j += 0x1;
+			}
+
+			table[i] = j;
+		}
+
+		table
+	}
+
+	/// Finds the first occurrence of a string slice.
+	///
+	/// The returned index is in octets.
+	/// If `needle` is empty, then `Some(0)` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn find_str(&self, needle: &str) -> Option<usize> {
+		let len        = self.len();
+		let needle_len = needle.len();
+
+		if needle_len > len {
+			return None;
+		}
+
+		let mut i = 0x0;
+		while i <= len - needle_len {
+			if self.matches_at(i, needle) {
+				return Some(i);
+			}
+
+			i += 0x1;
+		}
+
+		None
+	}
+
+	/// Finds the first occurrence of a character.
+	///
+	/// The returned index is in octets.
+	#[inline]
+	#[must_use]
+	pub const fn find_char(&self, c: char) -> Option<usize> {
+		let mut buf = [0x00; 0x4];
+		let needle = c.encode_utf8(&mut buf);
+
+		self.find_str(needle)
+	}
+
+	/// Finds the last occurrence of a string slice.
+	///
+	/// The returned index is in octets.
+	/// If `needle` is empty, then the string's length is returned.
+	#[inline]
+	#[must_use]
+	pub const fn rfind_str(&self, needle: &str) -> Option<usize> {
+		let len        = self.len();
+		let needle_len = needle.len();
+
+		if needle_len > len {
+			return None;
+		}
+
+		let mut i = len - needle_len;
+		loop {
+			if self.matches_at(i, needle) {
+				return Some(i);
+			}
+
+			if i == 0x0 {
+				return None;
+			}
+
+			i -= 0x1;
+		}
+	}
+
+	/// Finds the last occurrence of a character.
+	///
+	/// The returned index is in octets.
+	#[inline]
+	#[must_use]
+	pub const fn rfind_char(&self, c: char) -> Option<usize> {
+		let mut buf = [0x00; 0x4];
+		let needle = c.encode_utf8(&mut buf);
+
+		self.rfind_str(needle)
+	}
+
+	/// Counts the non-overlapping occurrences of a string slice.
+	///
+	/// Occurrences are searched from left to right, with each match resuming after the end of the previous one.
+	/// For example, `"aa"` occurs twice in `"aaaaa"`.
+	///
+	/// If `needle` is empty, then it is considered to match at every character boundary -- as with [`str::matches`] -- and the amount of characters plus one is returned.
+	#[inline]
+	#[must_use]
+	pub const fn count_str(&self, needle: &str) -> usize {
+		let len        = self.len();
+		let needle_len = needle.len();
+
+		if needle_len == 0x0 {
+			let buf = self.as_bytes();
+
+			let mut count = 0x1;
+
+			let mut i = 0x0;
+			while i < len {
+				i     += utf8_char_len(buf[i]);
+				count += 0x1;
+			}
+
+			return count;
+		}
+
+		let mut count = 0x0;
+
+		let mut i = 0x0;
+		while i + needle_len <= len {
+			if self.matches_at(i, needle) {
+				count += 0x1;
+				i     += needle_len;
+			} else {
+				i += 0x1;
+			}
+		}
+
+		count
+	}
+
+	/// Counts the occurrences of a character.
+	#[inline]
+	#[must_use]
+	pub const fn count_char(&self, c: char) -> usize {
+		let mut buf = [0x00; 0x4];
+		let needle = c.encode_utf8(&mut buf);
+
+		self.count_str(needle)
+	}
+
+	/// Checks if the string contains a character.
+	///
+	/// Contrary to [`str::contains`], this method may be used in constant expressions.
+	#[inline]
+	#[must_use]
+	pub const fn contains_char(&self, c: char) -> bool {
+		self.find_char(c).is_some()
+	}
+
+	/// Finds all occurrences of a character.
+	///
+	/// The octet indices of the first `K` occurrences are returned in an array alongside the amount of indices that were written.
+	/// Remaining elements of the array are left as zero.
+	///
+	/// If the string contains more than `K` occurrences, then the search stops after the `K`th and the remaining occurrences are ignored.
+	///
+	/// This is the constant counterpart to calling [`str::match_indices`] with a character, and may for example be used to precompute split points in constant expressions.
+	#[inline]
+	#[must_use]
+	pub const fn find_all_into<const K: usize>(&self, needle: char) -> ([usize; K], usize) {
+		let mut buf = [0x00; 0x4];
+		let needle = needle.encode_utf8(&mut buf);
+
+		let len        = self.len();
+		let needle_len = needle.len();
+
+		let mut indices = [0x0; K];
+		let mut count   = 0x0;
+
+		let mut i = 0x0;
+		while count < K && i + needle_len <= len {
+			if self.matches_at(i, needle) {
+				indices[count] = i;
+
+				count += 0x1;
+				i     += needle_len;
+			} else {
+				i += 0x1;
+			}
+		}
+
+		(indices, count)
+	}
+
+	/// Checks if `needle` occurs at the octet index `index`.
+	///
+	/// As both strings are valid UTF-8, a match always starts on a character boundary.
+	#[inline]
+	#[must_use]
+	const fn matches_at(&self, index: usize, needle: &str) -> bool {
+		let buf    = self.as_bytes();
+		let needle = needle.as_bytes();
+
+		if index + needle.len() > buf.len() {
+			return false;
+		}
+
+		let mut i = 0x0;
+		while i < needle.len() {
+			if buf[index + i] != needle[i] {
+				return false;
+			}
+
+			i += 0x1;
+		}
+
+		true
+	}
+
+	/// Splits the string at an index, borrowing the two parts.
+	///
+	/// # Panics
+	///
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will panic.
+	#[inline(always)]
+	#[must_use]
+	pub const fn split_at(&self, mid: usize) -> (&str, &str) {
+		self.as_str().split_at(mid)
+	}
+
 	/// Splits the string at an index, borrowing the two parts.
 	///
-	/// # Panics
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will instead return [`None`].
+	#[inline(always)]
+	#[must_use]
+	pub const fn split_at_checked(&self, mid: usize) -> Option<(&str, &str)> {
+		self.as_str().split_at_checked(mid)
+	}
+
+	/// Splits the string at an index, mutably borrowing the two parts.
+	///
+	/// # Panics
+	///
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will panic.
+	#[inline(always)]
+	#[must_use]
+	pub const fn split_at_mut(&mut self, mid: usize) -> (&mut str, &mut str) {
+		self.as_mut_str().split_at_mut(mid)
+	}
+
+	/// Splits the string at an index, mutably borrowing the two parts.
+	///
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will instead return [`None`].
+	#[inline(always)]
+	#[must_use]
+	pub const fn split_at_mut_checked(&mut self, mid: usize) -> Option<(&mut str, &mut str)> {
+		self.as_mut_str().split_at_mut_checked(mid)
+	}
+
+	/// Splits the string at an index, copying the two parts into new strings.
+	///
+	/// # Panics
+	///
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will panic.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub const fn split_at_owned(&self, mid: usize) -> (Self, Self) {
+		let Some(parts) = self.split_at_owned_checked(mid) else {
+			panic!("cannot split string at non-character boundary");
+		};
+
+		parts
+	}
+
+	/// Splits the string at an index, copying the two parts into new strings.
+	///
+	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will instead return [`None`].
+	#[inline]
+	#[must_use]
+	pub const fn split_at_owned_checked(&self, mid: usize) -> Option<(Self, Self)> {
+		let Some((head, tail)) = self.split_at_checked(mid) else {
+			return None;
+		};
+
+		// SAFETY: Both parts are sub-strings of `self` and
+		// can therefore not be longer than `N`.
+		let head = unsafe { Self::from_str_unchecked(head) };
+		let tail = unsafe { Self::from_str_unchecked(tail) };
+
+		Some((head, tail))
+	}
+
+	/// Splits the string around the first occurrence of a delimiter.
+	///
+	/// The delimiter itself is not included in either part.
+	/// If the delimiter is not found, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn split_once(&self, delimiter: &str) -> Option<(&str, &str)> {
+		let Some(mid) = self.find_str(delimiter) else {
+			return None;
+		};
+
+		let (head, tail) = self.split_at(mid);
+		let (_, tail)    = tail.split_at(delimiter.len());
+
+		Some((head, tail))
+	}
+
+	/// Splits the string around the last occurrence of a delimiter.
+	///
+	/// The delimiter itself is not included in either part.
+	/// If the delimiter is not found, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn rsplit_once(&self, delimiter: &str) -> Option<(&str, &str)> {
+		let Some(mid) = self.rfind_str(delimiter) else {
+			return None;
+		};
+
+		let (head, tail) = self.split_at(mid);
+		let (_, tail)    = tail.split_at(delimiter.len());
+
+		Some((head, tail))
+	}
+
+	/// Borrows the leading ASCII whitespace of the string.
+	///
+	/// This is the longest prefix consisting only of ASCII whitespace, as defined by [`u8::is_ascii_whitespace`].
+	#[inline]
+	#[must_use]
+	pub const fn leading_whitespace(&self) -> &str {
+		let buf = self.as_bytes();
+
+		let mut len = 0x0;
+		while len < buf.len() && buf[len].is_ascii_whitespace() {
+			len += 0x1;
+		}
+
+		let (head, _) = self.split_at(len);
+		head
+	}
+
+	/// Splits the string by a delimiter.
+	///
+	/// Parts are split as per [`str::split`], meaning that leading and trailing delimiters yield empty parts.
+	#[inline(always)]
+	pub const fn split_str<'a>(&'a self, delim: &'a str) -> Split<'a, N> {
+		Split::new(self, delim)
+	}
+
+	/// Borrows a substring.
+	///
+	/// The substring starts at octet `start` and ends before octet `end`.
+	/// Contrary to indexing, this method does not panic.
+	///
+	/// If either index is not on a character boundary (including if it is out of bounds), or if `start` is greater than `end`, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn substr(&self, start: usize, end: usize) -> Option<&str> {
+		if start > end || !self.is_char_boundary(start) || !self.is_char_boundary(end) {
+			return None;
+		}
+
+		let (s, _) = self.as_str().split_at(end);
+		let (_, s) = s.split_at(start);
+
+		Some(s)
+	}
+
+	/// Borrows a substring by character indices.
+	///
+	/// The substring starts at the `chars.start`th character and ends before the `chars.end`th character.
+	/// See also [`substr`](Self::substr) for indexing by octets.
+	///
+	/// If either index is greater than the amount of characters in the string, or if the range starts after it ends, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn char_slice(&self, chars: Range<usize>) -> Option<&str> {
+		if chars.start > chars.end {
+			return None;
+		}
+
+		let Some(start) = self.char_to_byte(chars.start) else {
+			return None;
+		};
+
+		let Some(end) = self.char_to_byte(chars.end) else {
+			return None;
+		};
+
+		self.substr(start, end)
+	}
+
+	/// Borrows a range of octets in the string.
+	///
+	/// Contrary to [`substr`](Self::substr), the range does not need to be on character boundaries.
+	///
+	/// If the range ends after the string's length, or if it starts after it ends, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn byte_slice(&self, range: Range<usize>) -> Option<&[u8]> {
+		if range.start > range.end || range.end > self.len() {
+			return None;
+		}
+
+		let (s, _) = self.as_bytes().split_at(range.end);
+		let (_, s) = s.split_at(range.start);
+
+		Some(s)
+	}
+
+	/// Borrows the string without a prefix.
+	///
+	/// If the string does not start with `prefix`, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn strip_prefix_str(&self, prefix: &str) -> Option<&str> {
+		if !self.matches_at(0x0, prefix) {
+			return None;
+		}
+
+		let (_, tail) = self.split_at(prefix.len());
+		Some(tail)
+	}
+
+	/// Borrows the string without a suffix.
+	///
+	/// If the string does not end with `suffix`, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn strip_suffix_str(&self, suffix: &str) -> Option<&str> {
+		let Some(mid) = self.len().checked_sub(suffix.len()) else {
+			return None;
+		};
+
+		if !self.matches_at(mid, suffix) {
+			return None;
+		}
+
+		let (head, _) = self.split_at(mid);
+		Some(head)
+	}
+
+	/// Borrows the string without leading and trailing whitespace.
+	///
+	/// Whitespace is defined as per [`char::is_whitespace`].
+	/// Contrary to [`str::trim`], this method may also be used in constant expressions.
+	#[inline]
+	#[must_use]
+	pub const fn trim(&self) -> &str {
+		trim_end(trim_start(self.as_str()))
+	}
+
+	/// Borrows the string without leading whitespace.
+	///
+	/// Whitespace is defined as per [`char::is_whitespace`].
+	/// Contrary to [`str::trim_start`], this method may also be used in constant expressions.
+	#[inline]
+	#[must_use]
+	pub const fn trim_start(&self) -> &str {
+		trim_start(self.as_str())
+	}
+
+	/// Borrows the string without trailing whitespace.
+	///
+	/// Whitespace is defined as per [`char::is_whitespace`].
+	/// Contrary to [`str::trim_end`], this method may also be used in constant expressions.
+	#[inline]
+	#[must_use]
+	pub const fn trim_end(&self) -> &str {
+		trim_end(self.as_str())
+	}
+
+	/// Splits the string by whitespace into an array.
+	///
+	/// The first `K` non-empty, whitespace-delimited tokens are returned alongside the amount of tokens that were written.
+	/// Remaining elements of the array are left as empty strings.
+	/// Leading, trailing, and consecutive whitespace never yield empty tokens.
+	///
+	/// If the string contains more than `K` tokens, then splitting stops after the `K`th and the remaining tokens are ignored.
+	///
+	/// Whitespace is defined as per [`char::is_whitespace`].
+	/// Contrary to [`str::split_whitespace`], this method may also be used in constant expressions.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use conststr::{String, string};
+	///
+	/// const S: String<0x10> = string!("  foo   bar baz ");
+	///
+	/// const TOKENS: ([&str; 0x4], usize) = S.split_whitespace_into();
+	///
+	/// assert_eq!(TOKENS, (["foo", "bar", "baz", ""], 0x3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn split_whitespace_into<const K: usize>(&self) -> ([&str; K], usize) {
+		let s   = self.as_str();
+		let len = s.len();
+
+		let mut tokens = [""; K];
+		let mut count  = 0x0;
+
+		let mut i = 0x0;
+		while count < K && i < len {
+			// Skip any whitespace before the token.
+
+			let (c, char_len) = decode_utf8(s, i);
+
+			if is_whitespace(c) {
+				i += char_len;
+				continue;
+			}
+
+			let start = i;
+
+			while i < len {
+				let (c, char_len) = decode_utf8(s, i);
+
+				if is_whitespace(c) {
+					break;
+				}
+
+				i += char_len;
+			}
+
+			let (head, _) = s.split_at(i);
+			let (_, token) = head.split_at(start);
+
+			tokens[count] = token;
+			count += 0x1;
+		}
+
+		(tokens, count)
+	}
+
+	/// Borrows the longest prefix that fits within a given display width.
+	///
+	/// The display width of each character is determined as per [`UnicodeWidthChar::width`], with wide characters (e.g. CJK ideographs) counting as two columns.
+	/// Control characters are counted as having no width.
+	#[cfg(feature = "unicode-width")]
+	#[cfg_attr(doc, doc(cfg(feature = "unicode-width")))]
+	#[inline]
+	#[must_use]
+	pub fn prefix_within_width(&self, max_width: usize) -> &str {
+		let mut width = 0x0;
+
+		for (i, c) in self.char_indices() {
+			width += c.width().unwrap_or_default();
+
+			if width > max_width {
+				let (head, _) = self.split_at(i);
+				return head;
+			}
+		}
+
+		self
+	}
+
+	/// Parses the string into another type.
+	///
+	/// This is equivalent to [`str::parse`].
+	///
+	/// # Errors
+	///
+	/// If the string could not be parsed, then the error of `T`'s [`FromStr`] implementation is returned.
+	#[inline(always)]
+	pub fn parse_into<T: FromStr>(&self) -> Result<T, T::Err> {
+		self.as_str().parse()
+	}
+
+	/// Parses the string as a decimal, unsigned integer.
+	///
+	/// Contrary to [`parse_into`](Self::parse_into), this method may be used in constant expressions.
+	/// An optional leading `+` sign is accepted, as with the [`FromStr`] implementation of [`u64`].
+	///
+	/// # Errors
+	///
+	/// If the string is empty, contains non-digit characters, or denotes a value that is too large for [`u64`], then an error is returned.
+	#[inline]
+	pub const fn parse_u64(&self) -> Result<u64, ParseIntError> {
+		u64::from_str_radix(self.as_str(), 0xA)
+	}
+
+	/// Parses a list of integers separated by `delimiter`.
+	///
+	/// The parsed values are returned in an array alongside the amount of values that were parsed.
+	/// Remaining elements of the array are left as zero.
+	/// An empty string is parsed as an empty list.
+	///
+	/// # Errors
+	///
+	/// If any field is not a valid integer, or if the string contains more than `K` fields, then an error is returned.
+	#[inline]
+	pub fn parse_int_list<const K: usize>(&self, delimiter: char) -> Result<([i64; K], usize), ParseError> {
+		let mut buf   = [0x0; K];
+		let mut count = 0x0;
+
+		if self.is_empty() {
+			return Ok((buf, count));
+		}
+
+		let fields = self.split(delimiter);
+
+		let field_count = fields.clone().count();
+		if field_count > K {
+			return Err(LengthError {
+				remaining: K,
+				count:     field_count,
+			}.into());
+		}
+
+		for (value, field) in buf.iter_mut().zip(fields) {
+			*value = field.parse()?;
+			count += 0x1;
+		}
+
+		Ok((buf, count))
+	}
+
+	/// Parses the string as a duration.
+	///
+	/// The string must consist of an unsigned integer immediately followed by one of the units `ns`, `us`, `ms`, `s`, `m`, or `h` -- e.g. `500ms` or `2m`.
+	/// Compound durations (such as `1h30m`) are not supported.
+	///
+	/// If the string could not be parsed, or if the duration would overflow, then [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub fn parse_duration(&self) -> Option<Duration> {
+		let mid = self.find(|c: char| !c.is_ascii_digit())?;
+
+		let (value, unit) = self.split_at(mid);
+
+		let value: u64 = value.parse().ok()?;
+
+		let duration = match unit {
+			"ns" => Duration::from_nanos(value),
+			"us" => Duration::from_micros(value),
+			"ms" => Duration::from_millis(value),
+			"s"  => Duration::from_secs(value),
+			"m"  => Duration::from_secs(value.checked_mul(0x3C)?),
+			"h"  => Duration::from_secs(value.checked_mul(0xE10)?),
+
+			_ => return None,
+		};
+
+		Some(duration)
+	}
+
+	/// Iterates over all numbers in the string.
+	///
+	/// Each maximal run of ASCII digits is yielded as a single, decimal number.
+	/// Numbers that do not fit in [`u64`] saturate to [`u64::MAX`].
+	#[inline]
+	pub fn numbers(&self) -> impl Iterator<Item = u64> + '_ {
+		self
+			.as_bytes()
+			.split(|octet| !octet.is_ascii_digit())
+			.filter(|digits| !digits.is_empty())
+			.map(|digits| {
+				digits.iter().fold(0x0, |value: u64, &octet| {
+					let digit = u64::from(octet - b'0');
+
+					value.saturating_mul(0xA).saturating_add(digit)
+				})
+			})
+	}
+
+	/// Iterates over all lines in the string as separate strings.
+	///
+	/// Lines are split in the same way as with [`str::lines`].
+	/// Each line is yielded as a string with a capacity of `M` octets.
+	///
+	/// # Errors
+	///
+	/// If a line cannot be contained within `M` octets, then an error is yielded for that line.
+	/// Any following lines are still yielded.
+	#[inline]
+	pub fn lines_into<const M: usize>(&self) -> impl Iterator<Item = Result<String<M>, LengthError>> + '_ {
+		self.lines().map(String::from_str)
+	}
+
+	/// Iterates over the lines of the string.
+	///
+	/// Lines are split as per [`str::lines`], i.e. on line feeds with an optional, preceding carriage return.
+	/// A trailing line ending does not yield a final, empty line.
+	///
+	/// Contrary to [`str::lines`], the returned iterator may also be driven in constant expressions.
+	#[inline(always)]
+	pub const fn lines_iter(&self) -> Lines<'_, N> {
+		Lines::new(self)
+	}
+
+	/// Iterates over chunks of the string.
+	///
+	/// Each chunk is at most `cell_bytes` octets long and never splits a character.
+	/// A character that is itself longer than `cell_bytes` is yielded as its own chunk.
+	///
+	/// # Panics
+	///
+	/// If `cell_bytes` is zero, then this method will panic.
+	#[inline]
+	#[track_caller]
+	pub fn display_chunks(&self, cell_bytes: usize) -> impl Iterator<Item = &str> + '_ {
+		assert!(cell_bytes != 0x0, "cannot split string into empty chunks");
+
+		let mut rest = self.as_str();
+
+		iter::from_fn(move || {
+			if rest.is_empty() {
+				return None;
+			}
+
+			let mut mid = cell_bytes.min(rest.len());
+
+			while !rest.is_char_boundary(mid) {
+				mid -= 0x1;
+			}
+
+			if mid == 0x0 {
+				mid = rest.chars().next().map_or(0x0, char::len_utf8);
+			}
+
+			let (chunk, tail) = rest.split_at(mid);
+			rest = tail;
+
+			Some(chunk)
+		})
+	}
+
+	/// Iterates over the characters of the string.
+	///
+	/// See [`str::chars`] for more information.
+	#[inline(always)]
+	pub fn chars(&self) -> Chars<'_> {
+		self.as_str().chars()
+	}
+
+	/// Iterates over the characters of the string and their positions.
+	///
+	/// See [`str::char_indices`] for more information.
+	#[inline(always)]
+	pub fn char_indices(&self) -> CharIndices<'_> {
+		self.as_str().char_indices()
+	}
+
+	/// Converts the string into an iterator over its characters.
+	///
+	/// Contrary to [`chars`](Self::chars), the returned iterator owns the string.
+	#[inline(always)]
+	pub const fn into_chars(self) -> IntoChars<N> {
+		IntoChars::new(self)
+	}
+
+	/// Returns the current length of the string.
+	///
+	/// Remember that this value only denotes the octet count and **not** the amount of characters, graphemes, etc.
+	#[inline(always)]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Sets the length of the string.
+	///
+	/// This is mainly useful after writing directly into the buffer, e.g. through [`as_mut_ptr`](Self::as_mut_ptr).
+	/// See also [`try_apply`](Self::try_apply) for a safe alternative.
+	///
+	/// # Safety
+	///
+	/// `new_len` must not be greater than `N`, and the first `new_len` octets of the buffer must be valid UTF-8.
+	/// Both requirements are checked if debug assertions are enabled.
+	#[inline]
+	#[track_caller]
+	pub const unsafe fn set_len(&mut self, new_len: usize) {
+		debug_assert!(new_len <= N, "cannot set length beyond capacity");
+
+		self.len = new_len;
+		self.debug_assert_invariants();
+	}
+
+	/// Checks if the string is empty, i.e. no characters are contained.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len() == 0x0
+	}
+
+	/// Checks if the string is blank, i.e. empty or only containing whitespace.
+	///
+	/// Whitespace is defined as in [`char::is_whitespace`], meaning that non-ASCII whitespace (such as no-break spaces) is also recognised.
+	#[inline]
+	#[must_use]
+	pub const fn is_blank(&self) -> bool {
+		trim_start(self.as_str()).is_empty()
+	}
+
+	/// Gets the index of the next character boundary.
+	///
+	/// # Panics
+	///
+	/// If `index` references past the last boundary, then this method will panic.
+	#[inline]
+	#[track_caller]
+	#[must_use]
+	const fn prev_char_boundary(&self, index: usize) -> usize {
+		assert!(
+			index <= self.len(),
+			"cannot find character past string",
+		);
+
+		// NOTE: `0x0` is always considered a boundary.
+		let mut i = index.saturating_sub(0x1);
+
+		while i > 0x0 {
+			let octet = self.as_bytes()[i];
+
+			if octet & 0b11000000 != 0b10000000 {
+				break;
+			}
+
+			i -= 0x1;
+		}
+
+		debug_assert!(self.is_char_boundary(i));
+
+		i
+	}
+
+	/// Asserts that the string upholds its invariants.
+	///
+	/// That is, the length may not exceed the capacity, and the used octets must be valid UTF-8.
+	/// This method does nothing unless debug assertions are enabled.
+	#[inline(always)]
+	#[track_caller]
+	const fn debug_assert_invariants(&self) {
+		debug_assert!(self.len <= N, "string is longer than its capacity");
+
+		debug_assert!(
+			count_invalid_utf8(self.as_bytes()) == 0x0,
+			"string contains invalid UTF-8",
+		);
+	}
+
+	/// Gets the octet index of the boundary preceding the `char_index`th character.
+	///
+	/// # Panics
+	///
+	/// If `char_index` is greater than the amount of characters in the string, then this method will panic.
+	#[inline]
+	#[track_caller]
+	#[must_use]
+	const fn char_boundary_at(&self, char_index: usize) -> usize {
+		let Some(index) = self.char_to_byte(char_index) else {
+			panic!("cannot find character past string");
+		};
+
+		index
+	}
+
+	/// Checks if a specified index is on the boundary of a character.
+	///
+	/// In this case, character is defined as a set of one to four UTF-8 octets that represent a Unicode code point (specifically a Unicode scalar).
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_char_boundary(&self, index: usize) -> bool {
+		self.as_str().is_char_boundary(index)
+	}
+
+	/// Iterates over the character boundaries of the string.
+	///
+	/// Both zero and the length of the string are yielded, meaning that an empty string yields a single boundary.
+	#[inline(always)]
+	pub const fn char_boundaries(&self) -> CharBoundaries<'_, N> {
+		CharBoundaries::new(self)
+	}
+
+	/// Finds the closest character boundary at or before `index`.
+	///
+	/// If `index` is past the end of the string, then the length of the string is returned.
+	#[inline]
+	#[must_use]
+	pub const fn floor_char_boundary(&self, index: usize) -> usize {
+		if index >= self.len() {
+			return self.len();
+		}
+
+		self.prev_char_boundary(index + 0x1)
+	}
+
+	/// Finds the closest character boundary at or after `index`.
+	///
+	/// If `index` is past the end of the string, then the length of the string is returned.
+	#[inline]
+	#[must_use]
+	pub const fn ceil_char_boundary(&self, index: usize) -> usize {
+		if index >= self.len() {
+			return self.len();
+		}
+
+		let mut i = index;
+
+		while !self.is_char_boundary(i) {
+			i += 0x1;
+		}
+
+		i
+	}
+
+	/// Gets the first character of the string.
+	///
+	/// If the string is empty, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn first_char(&self) -> Option<char> {
+		if self.is_empty() {
+			return None;
+		}
+
+		let (c, _) = decode_utf8(self.as_str(), 0x0);
+		Some(c)
+	}
+
+	/// Gets the last character of the string.
+	///
+	/// If the string is empty, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn last_char(&self) -> Option<char> {
+		if self.is_empty() {
+			return None;
+		}
+
+		let index = self.prev_char_boundary(self.len());
+
+		let (c, _) = decode_utf8(self.as_str(), index);
+		Some(c)
+	}
+
+	/// Gets the `n`th character of the string.
+	///
+	/// If the string contains `n` or fewer characters, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn nth_char(&self, n: usize) -> Option<char> {
+		let Some(index) = self.char_to_byte(n) else {
+			return None;
+		};
+
+		if index >= self.len() {
+			return None;
+		}
+
+		let (c, _) = decode_utf8(self.as_str(), index);
+		Some(c)
+	}
+
+	/// Converts a character index to an octet index.
+	///
+	/// The returned index denotes the boundary preceding the `char_index`th character.
+	/// The amount of characters in the string maps to the length of the string.
+	///
+	/// If `char_index` is greater than the amount of characters in the string, then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn char_to_byte(&self, char_index: usize) -> Option<usize> {
+		let buf = self.as_bytes();
+
+		let mut index = 0x0;
+		let mut count = 0x0;
+
+		while count < char_index {
+			if index >= buf.len() {
+				return None;
+			}
+
+			index += utf8_char_len(buf[index]);
+			count += 0x1;
+		}
+
+		Some(index)
+	}
+
+	/// Converts an octet index to a character index.
+	///
+	/// The returned index denotes the amount of characters preceding `byte_index`.
+	/// The length of the string maps to the amount of characters in the string.
 	///
-	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will panic.
+	/// If `byte_index` is not on a character boundary (including if it is out of bounds), then `None` is returned.
+	#[inline]
+	#[must_use]
+	pub const fn byte_to_char(&self, byte_index: usize) -> Option<usize> {
+		if !self.is_char_boundary(byte_index) {
+			return None;
+		}
+
+		let buf = self.as_bytes();
+
+		let mut index = 0x0;
+		let mut count = 0x0;
+
+		while index < byte_index {
+			index += utf8_char_len(buf[index]);
+			count += 0x1;
+		}
+
+		Some(count)
+	}
+
+	/// Checks if the entire string is also valid in ASCII.
 	#[inline(always)]
 	#[must_use]
-	pub const fn split_at(&self, mid: usize) -> (&str, &str) {
-		self.as_str().split_at(mid)
+	pub const fn is_ascii(&self) -> bool {
+		self.as_str().is_ascii()
 	}
 
-	/// Splits the string at an index, borrowing the two parts.
+	/// Checks if the string is equal to a string slice.
 	///
-	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will instead return [`None`].
-	#[inline(always)]
+	/// This is equivalent to the [`PartialEq<str>`] implementation, but may also be used in constant expressions.
+	#[inline]
 	#[must_use]
-	pub const fn split_at_checked(&self, mid: usize) -> Option<(&str, &str)> {
-		self.as_str().split_at_checked(mid)
+	pub const fn equals(&self, other: &str) -> bool {
+		self.len() == other.len() && self.matches_at(0x0, other)
 	}
 
-	/// Splits the string at an index, mutably borrowing the two parts.
+	/// Checks if the string is equal to another string, including its capacity.
 	///
-	/// # Panics
+	/// This is in contrast to the [`PartialEq`] implementation, which only compares the contents of the strings.
+	#[inline]
+	#[must_use]
+	pub fn eq_exact<const M: usize>(&self, other: &String<M>) -> bool {
+		N == M && self == other
+	}
+
+	/// Checks if the string is a rotation of another string.
 	///
-	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will panic.
-	#[inline(always)]
+	/// Strings are rotated by whole characters, meaning that `self` must equal `other` with some leading characters moved to its end.
+	/// Every string is a rotation of itself, including the empty string.
+	#[inline]
 	#[must_use]
-	pub const fn split_at_mut(&mut self, mid: usize) -> (&mut str, &mut str) {
-		self.as_mut_str().split_at_mut(mid)
+	pub fn is_rotation_of<const M: usize>(&self, other: &String<M>) -> bool {
+		let this  = self.as_str();
+		let other = other.as_str();
+
+		if this.len() != other.len() {
+			return false;
+		}
+
+		if this.is_empty() {
+			return true;
+		}
+
+		// Compare each rotation of `other` in two halves
+		// instead of scanning through `other + other`.
+
+		other.char_indices().any(|(mid, _)| {
+			let (prefix, suffix) = other.split_at(mid);
+
+			this.split_at_checked(suffix.len())
+				.is_some_and(|(head, tail)| head == suffix && tail == prefix)
+		})
 	}
 
-	/// Splits the string at an index, mutably borrowing the two parts.
+	/// Computes the Hamming distance to another string.
 	///
-	/// If `mid` is not exactly on the boundary of a chacter (as per [`is_char_boundary`](Self::is_char_boundary)), then this method will instead return [`None`].
-	#[inline(always)]
+	/// The distance is the amount of positions at which the two strings' characters differ.
+	/// If the strings do not contain the same amount of characters, then `None` is returned.
+	#[inline]
 	#[must_use]
-	pub const fn split_at_mut_checked(&mut self, mid: usize) -> Option<(&mut str, &mut str)> {
-		self.as_mut_str().split_at_mut_checked(mid)
+	pub fn hamming_distance<const M: usize>(&self, other: &String<M>) -> Option<usize> {
+		let mut lhs = self.chars();
+		let mut rhs = other.chars();
+
+		let mut distance = 0x0;
+
+		loop {
+			match (lhs.next(), rhs.next()) {
+				(Some(a), Some(b)) => distance += usize::from(a != b),
+				(None,    None)    => break Some(distance),
+				_                  => break None,
+			}
+		}
 	}
 
-	/// Returns the current length of the string.
+	/// Checks if the string is equal to another string, ignoring ASCII case.
 	///
-	/// Remember that this value only denotes the octet count and **not** the amount of characters, graphemes, etc.
-	#[inline(always)]
+	/// Octets are compared one by one with ASCII letters folded to the same case.
+	/// Non-ASCII octets must match exactly.
+	#[inline]
 	#[must_use]
-	pub const fn len(&self) -> usize {
-		self.len
+	pub const fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+		let lhs = self.as_bytes();
+		let rhs = other.as_bytes();
+
+		if lhs.len() != rhs.len() {
+			return false;
+		}
+
+		let mut i = 0x0;
+		while i < lhs.len() {
+			if !lhs[i].eq_ignore_ascii_case(&rhs[i]) {
+				return false;
+			}
+
+			i += 0x1;
+		}
+
+		true
 	}
 
-	/// Checks if the string is empty, i.e. no characters are contained.
-	#[inline(always)]
+	/// Finds the first option that is equal to the string, ignoring ASCII case.
+	///
+	/// Options are compared as with [`eq_ignore_ascii_case`](Self::eq_ignore_ascii_case).
+	/// The index of the first matching option is returned, or `None` if no options match.
+	#[inline]
 	#[must_use]
-	pub const fn is_empty(&self) -> bool {
-		self.len() == 0x0
+	pub const fn matches_any_ignore_case(&self, options: &[&str]) -> Option<usize> {
+		let mut i = 0x0;
+		while i < options.len() {
+			if self.eq_ignore_ascii_case(options[i]) {
+				return Some(i);
+			}
+
+			i += 0x1;
+		}
+
+		None
 	}
 
-	/// Gets the index of the next character boundary.
+	/// Checks if the string is a valid email local-part.
 	///
-	/// # Panics
+	/// The string is tested against the `dot-atom` syntax of RFC 5322, i.e. it must consist of ASCII letters, digits, and any of the symbols ``!#$%&'*+-/=?^_`{|}~``, optionally separated by single dots.
+	/// Dots may not appear at the start or end of the string.
 	///
-	/// If `index` references past the last boundary, then this method will panic.
+	/// Quoted local-parts are not accepted.
 	#[inline]
-	#[track_caller]
 	#[must_use]
-	const fn prev_char_boundary(&self, index: usize) -> usize {
-		assert!(
-			index <= self.len(),
-			"cannot find character past string",
-		);
+	pub const fn is_valid_email_localpart(&self) -> bool {
+		let buf = self.as_bytes();
+		let len = buf.len();
 
-		// NOTE: `0x0` is always considered a boundary.
-		let mut i = index.saturating_sub(0x1);
+		if len == 0x0 {
+			return false;
+		}
 
-		while i > 0x0 {
-			let octet = self.as_bytes()[i];
+		let mut i = 0x0;
+		while i < len {
+			let octet = buf[i];
 
-			if octet & 0b11000000 != 0b10000000 {
-				break;
+			let is_valid = match octet {
+				b'.' => {
+					// Dots must be surrounded by other char-
+					// acters.
+					i != 0x0 && i != len - 0x1 && buf[i - 0x1] != b'.'
+				}
+
+				b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'/' | b'=' | b'?' | b'^' | b'_' | b'`' | b'{' | b'|' | b'}' | b'~' => true,
+
+				_ => octet.is_ascii_alphanumeric(),
+			};
+
+			if !is_valid {
+				return false;
 			}
 
-			i -= 0x1;
+			i += 0x1;
 		}
 
-		debug_assert!(self.is_char_boundary(i));
-
-		i
+		true
 	}
 
-	/// Checks if a specified index is on the boundary of a character.
+	/// Checks that the string is within the given octet and character limits.
 	///
-	/// In this case, character is defined as a set of one to four UTF-8 octets that represent a Unicode code point (specifically a Unicode scalar).
-	#[inline(always)]
-	#[must_use]
-	pub const fn is_char_boundary(&self, index: usize) -> bool {
-		self.as_str().is_char_boundary(index)
-	}
+	/// # Errors
+	///
+	/// If the string is longer than `max_bytes` octets, then an error denoting the octet count is returned.
+	/// Otherwise, if the string contains more than `max_chars` characters, then an error denoting the character count is returned.
+	#[inline]
+	pub const fn validate_limits(&self, max_bytes: usize, max_chars: usize) -> Result<(), LengthError> {
+		let buf = self.as_bytes();
 
-	/// Checks if the entire string is also valid in ASCII.
-	#[inline(always)]
-	#[must_use]
-	pub const fn is_ascii(&self) -> bool {
-		self.as_str().is_ascii()
+		if buf.len() > max_bytes {
+			return Err(LengthError {
+				remaining: max_bytes,
+				count:     buf.len(),
+			});
+		}
+
+		let mut i     = 0x0;
+		let mut count = 0x0;
+
+		while i < buf.len() {
+			i     += utf8_char_len(buf[i]);
+			count += 0x1;
+		}
+
+		if count > max_chars {
+			return Err(LengthError {
+				remaining: max_chars,
+				count,
+			});
+		}
+
+		Ok(())
 	}
 
 	/// Gets a pointer to the first octet.
@@ -593,46 +3210,276 @@ impl<const N: usize> String<N> {
 		let ptr = self.as_mut_ptr();
 		let len = self.len();
 
-		unsafe { slice::from_raw_parts_mut(ptr, len) }
+		unsafe { slice::from_raw_parts_mut(ptr, len) }
+	}
+
+	/// Safely borrows the string as a mutable byte slice.
+	///
+	/// The returned guard dereferences to the used octets of the string, which may be overwritten with arbitrary values.
+	/// When the guard is dropped, the string is truncated to its longest prefix that is valid UTF-8.
+	///
+	/// See [`BytesMutGuard`] for more information.
+	#[inline]
+	pub const fn bytes_mut_guard(&mut self) -> BytesMutGuard<'_, N> {
+		BytesMutGuard::new(self)
+	}
+
+	/// Applies a byte-level transform to the string.
+	///
+	/// The closure `f` is given the entire buffer of the string -- i.e. all `N` octets -- and returns the new length of the string.
+	/// The first octets of the buffer up to this length are then validated as UTF-8.
+	///
+	/// If validation succeeds, then the new length is committed.
+	/// Otherwise, the string is rolled back to its state from before calling `f`.
+	///
+	/// # Errors
+	///
+	/// If the transformed string is not valid UTF-8, then an error is returned and the string is left unchanged.
+	///
+	/// # Panics
+	///
+	/// If the returned length is greater than `N`, then this method will panic.
+	/// The string is left unchanged in this case as well.
+	#[inline]
+	#[track_caller]
+	pub fn try_apply<F: FnOnce(&mut [u8]) -> usize>(&mut self, f: F) -> Result<(), Utf8Error> {
+		let mut buf = self.buf;
+
+		let len = f(&mut buf);
+
+		assert!(len <= N, "cannot set length beyond capacity");
+
+		if let Err(e) = str::from_utf8(&buf[..len]) {
+			let i = e.valid_up_to();
+			let c = buf[i];
+
+			return Err(Utf8Error { value: c, index: i });
+		}
+
+		// SAFETY: We have tested that the first `len`
+		// octets are valid UTF-8.
+		*self = unsafe { Self::from_raw_parts(buf, len) };
+
+		Ok(())
+	}
+
+	/// Borrows the string as a C string.
+	///
+	/// The string must end in a null character, which is included in the returned C string as its terminator.
+	/// For a method that appends the terminator instead, see [`to_c_string`](Self::to_c_string).
+	///
+	/// # Errors
+	///
+	/// If the string does not end in a null character, or if it contains other null characters, then an error is returned.
+	#[inline]
+	pub const fn as_c_str(&self) -> Result<&CStr, CStrError> {
+		let Some((&0x00, head)) = self.as_bytes().split_last() else {
+			return Err(CStrError::NotNulTerminated);
+		};
+
+		if let Some(index) = self.find_char('\0') {
+			if index < head.len() {
+				return Err(CStrError::InteriorNul(index));
+			}
+		}
+
+		// SAFETY: The string ends in a null character and
+		// contains no others.
+		let s = unsafe { CStr::from_bytes_with_nul_unchecked(self.as_bytes()) };
+		Ok(s)
+	}
+
+	/// Borrows the string as a C string, writing a null terminator into the spare capacity.
+	///
+	/// The terminator is written just past the end of the string and is therefore not included in its length.
+	///
+	/// # Errors
+	///
+	/// If the string contains a null character, or if it has no room for the terminator (i.e. its length is `N`), then an error is returned.
+	#[allow(clippy::wrong_self_convention)]
+	#[inline]
+	pub const fn to_c_string(&mut self) -> Result<&CStr, CStrError> {
+		if let Some(index) = self.find_char('\0') {
+			return Err(CStrError::InteriorNul(index));
+		}
+
+		let len = self.len();
+
+		if len >= N {
+			return Err(CStrError::SmallBuffer(LengthError {
+				remaining: 0x0,
+				count:     0x1,
+			}));
+		}
+
+		self.buf[len] = 0x00;
+
+		let (buf, _) = self.buf.split_at(len + 0x1);
+
+		// SAFETY: The buffer ends in the null character
+		// that we have just written and contains no others.
+		let s = unsafe { CStr::from_bytes_with_nul_unchecked(buf) };
+		Ok(s)
+	}
+
+	/// Borrows the string as a string slice.
+	///
+	/// The range of the returned slice only includes characters that are "used."
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_str(&self) -> &str {
+		// SAFETY: We guarantee that all octets are always
+		// valid UTF-8 code octets.
+		unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	/// Mutably borrows the string as a string slice.
+	///
+	/// The range of the returned slice only includes characters that are "used."
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_mut_str(&mut self) -> &mut str {
+		unsafe {
+			let ptr = self.as_mut_ptr();
+			let len = self.len();
+
+			let data = slice::from_raw_parts_mut(ptr, len);
+			core::str::from_utf8_unchecked_mut(data)
+		}
+	}
+
+	/// Destructs the provided string into its raw parts.
+	///
+	/// The returned parts are valid to pass back to [`from_raw_parts`](Self::from_raw_parts).
+	///
+	/// The returned byte array is guaranteed to be fully initialised.
+	/// However, only octets up to an index of [`len`](Self::len) are also guaranteed to be valid UTF-8 code points.
+	#[inline(always)]
+	#[must_use]
+	pub const fn into_raw_parts(self) -> ([u8; N], usize) {
+		let Self { buf, len } = self;
+		(buf, len)
+	}
+
+	/// Moves the string into a buffer of a different capacity.
+	///
+	/// # Errors
+	///
+	/// If the string cannot be contained within `M` octets, then an error is returned.
+	#[inline]
+	pub const fn resize<const M: usize>(self) -> Result<String<M>, LengthError> {
+		let len = self.len();
+
+		if len > M {
+			return Err(LengthError {
+				remaining: M,
+				count:     len,
+			});
+		}
+
+		let mut buf = [0x00; M];
+
+		// SAFETY: We have checked that `buf` can contain
+		// `len` octets.
+		unsafe {
+			let src = self.as_ptr();
+			let dst = buf.as_mut_ptr();
+
+			copy_nonoverlapping(src, dst, len);
+		}
+
+		// SAFETY: The first `len` octets of `buf` have been
+		// copied from a valid string.
+		let this = unsafe { String::from_raw_parts(buf, len) };
+		Ok(this)
+	}
+
+	/// Moves the string into a buffer of a larger (or equal) capacity.
+	///
+	/// Contrary to [`resize`](Self::resize), this method cannot fail.
+	///
+	/// # Panics
+	///
+	/// If `M` is less than `N`, then compilation will fail.
+	#[inline]
+	#[must_use]
+	pub const fn grow<const M: usize>(self) -> String<M> {
+		const { assert!(M >= N, "cannot grow string into smaller buffer") };
+
+		match self.resize() {
+			Ok(this) => this,
+			Err(_)   => unreachable!(),
+		}
 	}
 
-	/// Borrows the string as a string slice.
+	/// Moves the string into a buffer of a different capacity.
 	///
-	/// The range of the returned slice only includes characters that are "used."
-	#[inline(always)]
+	/// Contrary to [`resize`](Self::resize), this method panics instead of returning an error.
+	/// This is mainly useful for repacking strings into a smaller buffer in constant expressions:
+	///
+	/// ```rust
+	/// use conststr::{String, string};
+	///
+	/// const NAME: String<0x40> = string!("hello");
+	/// const PACKED: String<0x8> = NAME.trim_capacity();
+	///
+	/// assert_eq!(PACKED, "hello");
+	/// ```
+	///
+	/// When used in constant expressions, strings that are too long will fail to compile:
+	///
+	/// ```rust,compile_fail
+	/// use conststr::{String, string};
+	///
+	/// const NAME: String<0x40> = string!("hello world");
+	/// const PACKED: String<0x8> = NAME.trim_capacity();
+	/// ```
+	///
+	/// # Panics
+	///
+	/// If the string cannot be contained within `M` octets, then this method will panic.
+	#[inline]
 	#[must_use]
-	pub const fn as_str(&self) -> &str {
-		// SAFETY: We guarantee that all octets are always
-		// valid UTF-8 code octets.
-		unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+	#[track_caller]
+	pub const fn trim_capacity<const M: usize>(self) -> String<M> {
+		match self.resize() {
+			Ok(this) => this,
+			Err(_)   => panic!("cannot trim capacity below length of string"),
+		}
 	}
 
-	/// Mutably borrows the string as a string slice.
+	/// Overwrites the string with the contents of another string.
 	///
-	/// The range of the returned slice only includes characters that are "used."
-	#[inline(always)]
-	#[must_use]
-	pub const fn as_mut_str(&mut self) -> &mut str {
+	/// Contrary to [`resize`](Self::resize), this method reuses the buffer of `self` and does not construct a new string.
+	///
+	/// # Errors
+	///
+	/// If `src` cannot be contained within `N` octets, then an error is returned and the string is left unchanged.
+	#[inline]
+	pub const fn copy_from<const M: usize>(&mut self, src: &String<M>) -> Result<(), LengthError> {
+		let len = src.len();
+
+		if len > N {
+			return Err(LengthError {
+				remaining: N,
+				count:     len,
+			});
+		}
+
+		// SAFETY: We have checked that `self` can contain
+		// `len` octets. The two strings cannot overlap as
+		// `self` is borrowed mutably.
 		unsafe {
-			let ptr = self.as_mut_ptr();
-			let len = self.len();
+			let src = src.as_ptr();
+			let dst = self.as_mut_ptr();
 
-			let data = slice::from_raw_parts_mut(ptr, len);
-			core::str::from_utf8_unchecked_mut(data)
+			copy_nonoverlapping(src, dst, len);
 		}
-	}
 
-	/// Destructs the provided string into its raw parts.
-	///
-	/// The returned parts are valid to pass back to [`from_raw_parts`](Self::from_raw_parts).
-	///
-	/// The returned byte array is guaranteed to be fully initialised.
-	/// However, only octets up to an index of [`len`](Self::len) are also guaranteed to be valid UTF-8 code points.
-	#[inline(always)]
-	#[must_use]
-	pub const fn into_raw_parts(self) -> ([u8; N], usize) {
-		let Self { buf, len } = self;
-		(buf, len)
+		self.len = len;
+		self.debug_assert_invariants();
+
+		Ok(())
 	}
 
 	/// Converts the constant string into a boxed string slice.
@@ -758,6 +3605,11 @@ impl<const N: usize> Decode for String<N> {
 	}
 }
 
+/// Strings use the same encoding as octet slices.
+/// [`Decode`] can therefore also be used on data that was encoded from <code>&[[u8]]</code>, with the octets being validated as UTF-8.
+///
+/// `DecodeBorrowed<[u8]>` is not implemented, however, as that would require [`Borrow<[u8]>`](Borrow).
+/// Borrowing as both [`str`] and octets would make the [`Hash`] implementation inconsistent with either of them.
 #[cfg(feature = "oct")]
 #[cfg_attr(doc, doc(cfg(feature = "oct")))]
 impl<const N: usize> DecodeBorrowed<str> for String<N> { }
@@ -786,9 +3638,12 @@ impl<const N: usize> DerefMut for String<N> {
 }
 
 impl<const N: usize> Display for String<N> {
+	/// Formats the string.
+	///
+	/// The width, fill, alignment, and precision flags of `f` are respected in the same way as for [`str`](prim@str).
 	#[inline]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		Display::fmt(self.as_str(), f)
+		f.pad(self.as_str())
 	}
 }
 
@@ -807,6 +3662,15 @@ impl<const N: usize> Encode for String<N> {
 
 impl<const N: usize> Eq for String<N> { }
 
+#[cfg(feature = "defmt")]
+#[cfg_attr(doc, doc(cfg(feature = "defmt")))]
+impl<const N: usize> Format for String<N> {
+	#[inline(always)]
+	fn format(&self, fmt: defmt::Formatter) {
+		self.as_str().format(fmt);
+	}
+}
+
 impl<const N: usize> FromIterator<char> for String<N> {
 	#[inline]
 	fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
@@ -832,6 +3696,14 @@ impl<const N: usize> FromStr for String<N> {
 }
 
 impl<const N: usize> Hash for String<N> {
+	/// Hashes the string.
+	///
+	/// Only the contents of the string are hashed, meaning that equal strings of different capacities hash identically.
+	/// This is consistent with the [`PartialEq`] implementation between different capacities.
+	///
+	/// The hash is identical to that of the equivalent [`str`], meaning that hash-based collections with string keys may be queried by <code>&[str]</code> through [`Borrow<str>`](Borrow).
+	/// Octet slices are not supported as query keys, as <code>[[u8]]</code> hashes differently from [`str`].
+	/// `Borrow<[u8]>` is therefore intentionally not implemented; instead, convert octets with [`str::from_utf8`] before querying.
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		self.as_str().hash(state)
@@ -870,6 +3742,16 @@ impl<const N: usize, const M: usize> PartialEq<String<M>> for String<N> {
 	}
 }
 
+impl<const N: usize> PartialEq<char> for String<N> {
+	#[inline]
+	fn eq(&self, other: &char) -> bool {
+		let mut buf = [0x00; 0x4];
+		let other = other.encode_utf8(&mut buf);
+
+		**self == *other
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 impl<const N: usize> PartialEq<Cow<'_, str>> for String<N> {
@@ -893,6 +3775,34 @@ impl<const N: usize> PartialEq<&str> for String<N> {
 	}
 }
 
+impl<const N: usize> PartialEq<[u8]> for String<N> {
+	#[inline(always)]
+	fn eq(&self, other: &[u8]) -> bool {
+		self.as_bytes() == other
+	}
+}
+
+impl<const N: usize> PartialEq<&[u8]> for String<N> {
+	#[inline(always)]
+	fn eq(&self, other: &&[u8]) -> bool {
+		self.as_bytes() == *other
+	}
+}
+
+impl<const N: usize, const M: usize> PartialEq<[u8; M]> for String<N> {
+	#[inline(always)]
+	fn eq(&self, other: &[u8; M]) -> bool {
+		self.as_bytes() == other
+	}
+}
+
+impl<const N: usize, const M: usize> PartialEq<&[u8; M]> for String<N> {
+	#[inline(always)]
+	fn eq(&self, other: &&[u8; M]) -> bool {
+		self.as_bytes() == *other
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 impl<const N: usize> PartialEq<alloc::string::String> for String<N> {
@@ -929,6 +3839,29 @@ impl<const N: usize, const M: usize> PartialOrd<String<M>> for String<N> {
 	}
 }
 
+impl<const N: usize> PartialOrd<str> for String<N> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+		self.as_str().partial_cmp(other)
+	}
+}
+
+impl<const N: usize> PartialOrd<&str> for String<N> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+		self.as_str().partial_cmp(*other)
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<const N: usize> PartialOrd<alloc::string::String> for String<N> {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &alloc::string::String) -> Option<Ordering> {
+		self.as_str().partial_cmp(other.as_str())
+	}
+}
+
 #[cfg(feature = "oct")]
 #[cfg_attr(doc, doc(cfg(feature = "oct")))]
 impl<const N: usize> SizedEncode for String<N> {
@@ -953,10 +3886,25 @@ impl<const N: usize> TryFrom<char> for String<N> {
 
 	#[inline(always)]
 	fn try_from(value: char) -> Result<Self, Self::Error> {
-		let mut buf = [0x00; 0x4];
-		let s = value.encode_utf8(&mut buf);
+		Self::from_char(value)
+	}
+}
+
+impl<const N: usize> TryFrom<&[char]> for String<N> {
+	type Error = LengthError;
+
+	#[inline]
+	fn try_from(value: &[char]) -> Result<Self, Self::Error> {
+		Self::try_from_iter(value.iter().copied())
+	}
+}
 
-		s.parse()
+impl<const N: usize, const M: usize> TryFrom<[char; M]> for String<N> {
+	type Error = LengthError;
+
+	#[inline]
+	fn try_from(value: [char; M]) -> Result<Self, Self::Error> {
+		Self::try_from_iter(value)
 	}
 }
 
@@ -969,6 +3917,62 @@ impl<const N: usize> TryFrom<&str> for String<N> {
 	}
 }
 
+impl<const N: usize> TryFrom<&[u8]> for String<N> {
+	type Error = StringError;
+
+	#[inline]
+	fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+		let len = value.len();
+
+		if len > N {
+			return Err(LengthError {
+				remaining: N,
+				count:     len,
+			}.into());
+		}
+
+		let s = match str::from_utf8(value) {
+			Ok(s) => s,
+
+			Err(e) => {
+				let i = e.valid_up_to();
+				let c = value[i];
+
+				return Err(Utf8Error { value: c, index: i }.into());
+			}
+		};
+
+		// SAFETY: We have tested that `s` is not too long.
+		let this = unsafe { Self::from_str_unchecked(s) };
+		Ok(this)
+	}
+}
+
+/// See [`from_utf8`](String::from_utf8).
+///
+/// The length of the resulting string is always `M`, meaning that any null octets -- including trailing ones -- are kept as characters.
+/// Converting a string into an array and back therefore only round-trips if the string has no padding.
+/// To remove the padding, use [`from_nul_padded`](String::from_nul_padded) instead.
+impl<const N: usize, const M: usize> TryFrom<[u8; M]> for String<N> {
+	type Error = Utf8Error;
+
+	#[inline(always)]
+	fn try_from(value: [u8; M]) -> Result<Self, Self::Error> {
+		Self::from_utf8(value)
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<const N: usize> TryFrom<Cow<'_, str>> for String<N> {
+	type Error = <Self as FromStr>::Err;
+
+	#[inline(always)]
+	fn try_from(value: Cow<str>) -> Result<Self, Self::Error> {
+		Self::from_str(&value)
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 impl<const N: usize> TryFrom<alloc::string::String> for String<N> {
@@ -980,6 +3984,47 @@ impl<const N: usize> TryFrom<alloc::string::String> for String<N> {
 	}
 }
 
+#[cfg(feature = "ufmt")]
+#[cfg_attr(doc, doc(cfg(feature = "ufmt")))]
+impl<const N: usize> uDisplay for String<N> {
+	#[inline(always)]
+	fn fmt<W: uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<W>) -> Result<(), W::Error> {
+		uDisplay::fmt(self.as_str(), f)
+	}
+}
+
+#[cfg(feature = "ufmt")]
+#[cfg_attr(doc, doc(cfg(feature = "ufmt")))]
+impl<const N: usize> uWrite for String<N> {
+	type Error = LengthError;
+
+	#[inline(always)]
+	fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+		self.push_str(s)
+	}
+
+	#[inline(always)]
+	fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+		self.push(c)
+	}
+}
+
+/// Writes formatted text into the string.
+///
+/// If the written text cannot be contained in the string, then an error is returned.
+/// Any text that was written before the error occurred is kept.
+impl<const N: usize> fmt::Write for String<N> {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.push_str(s).map_err(|_| fmt::Error)
+	}
+
+	#[inline]
+	fn write_char(&mut self, c: char) -> fmt::Result {
+		self.push(c).map_err(|_| fmt::Error)
+	}
+}
+
 /// See [`into_boxed_str`](String::into_boxed_str).
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
@@ -1000,6 +4045,24 @@ impl<const N: usize> From<String<N>> for alloc::string::String {
 	}
 }
 
+/// Returns the backing buffer of the string.
+///
+/// All octets after the length of the string are set to null.
+///
+/// Note that this conversion does not round-trip with [`TryFrom`], as that keeps the padding as null characters.
+/// Use [`from_nul_padded`](String::from_nul_padded) for the inverse conversion instead.
+impl<const N: usize> From<String<N>> for [u8; N] {
+	#[inline]
+	fn from(value: String<N>) -> Self {
+		let (mut buf, len) = value.into_raw_parts();
+
+		let (_, padding) = buf.split_at_mut(len);
+		padding.fill(0x00);
+
+		buf
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 impl<const N: usize> PartialEq<String<N>> for alloc::string::String {
@@ -1009,6 +4072,129 @@ impl<const N: usize> PartialEq<String<N>> for alloc::string::String {
 	}
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<const N: usize> PartialEq<String<N>> for Cow<'_, str> {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		**self == **other
+	}
+}
+
+impl<const N: usize> PartialEq<String<N>> for char {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		*other == *self
+	}
+}
+
+impl<const N: usize> PartialEq<String<N>> for str {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		self == other.as_str()
+	}
+}
+
+impl<const N: usize> PartialEq<String<N>> for &str {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		*self == other.as_str()
+	}
+}
+
+impl<const N: usize> PartialEq<String<N>> for [u8] {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		self == other.as_bytes()
+	}
+}
+
+impl<const N: usize> PartialEq<String<N>> for &[u8] {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		*self == other.as_bytes()
+	}
+}
+
+impl<const N: usize, const M: usize> PartialEq<String<N>> for [u8; M] {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		self == other.as_bytes()
+	}
+}
+
+impl<const N: usize, const M: usize> PartialEq<String<N>> for &[u8; M] {
+	#[inline(always)]
+	fn eq(&self, other: &String<N>) -> bool {
+		*self == other.as_bytes()
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<const N: usize> PartialOrd<String<N>> for alloc::string::String {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &String<N>) -> Option<Ordering> {
+		self.as_str().partial_cmp(other.as_str())
+	}
+}
+
+impl<const N: usize> PartialOrd<String<N>> for str {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &String<N>) -> Option<Ordering> {
+		self.partial_cmp(other.as_str())
+	}
+}
+
+impl<const N: usize> PartialOrd<String<N>> for &str {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &String<N>) -> Option<Ordering> {
+		(*self).partial_cmp(other.as_str())
+	}
+}
+
+/// Returns the greater of two capacities.
+///
+/// Contrary to [`Ord::max`], this function may be used in constant expressions, e.g. for determining the capacity of a string that must be able to contain either of two other strings.
+///
+/// Note that stable Rust does not allow using generic parameters in such expressions; the capacities must therefore be concrete.
+///
+/// # Examples
+///
+/// ```rust
+/// use conststr::{String, max_capacity};
+///
+/// const CAPACITY: usize = max_capacity(String::<0x8>::CAPACITY, String::<0x4>::CAPACITY);
+///
+/// assert_eq!(CAPACITY, 0x8);
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn max_capacity(a: usize, b: usize) -> usize {
+	if a >= b { a } else { b }
+}
+
+/// Returns the lesser of two capacities.
+///
+/// Contrary to [`Ord::min`], this function may be used in constant expressions.
+/// See also [`max_capacity`].
+#[inline(always)]
+#[must_use]
+pub const fn min_capacity(a: usize, b: usize) -> usize {
+	if a <= b { a } else { b }
+}
+
+// NOTE: This function is used by the `match_string`
+// macro for comparing strings in constant expres-
+// sions. Please do not call it directly. It is not
+// a breaking change if it is removed.
+#[doc(hidden)]
+#[inline(always)]
+#[must_use]
+pub const fn __match_string<const N: usize>(s: &String<N>, pattern: &str) -> bool {
+	s.equals(pattern)
+}
+
 // NOTE: This function is used by the `str` macro
 // to circumvent itself using code which may be
 // forbidden by the macro user's lints. While this
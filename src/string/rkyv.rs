@@ -0,0 +1,175 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "rkyv")]
+
+use crate::String;
+use crate::error::{LengthError, Utf8Error};
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Deref;
+use core::str;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::primitive::{ArchivedUsize, FixedUsize};
+use rkyv::rancor::{Fallible, Source};
+
+/// An archived [`String`].
+///
+/// This is a plain octet array alongside its length, and can therefore be accessed directly without deserialisation.
+/// The length is validated -- and the contents are checked to be valid UTF-8 -- when the archive is checked.
+#[cfg_attr(doc, doc(cfg(feature = "rkyv")))]
+#[repr(C)]
+pub struct ArchivedString<const N: usize> {
+	len: ArchivedUsize,
+	buf: [u8; N],
+}
+
+impl<const N: usize> ArchivedString<N> {
+	/// Returns the length of the archived string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len.to_native() as usize
+	}
+
+	/// Checks if the archived string is empty.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len() == 0x0
+	}
+
+	/// Borrows the archived string as a string slice.
+	#[inline]
+	#[must_use]
+	pub const fn as_str(&self) -> &str {
+		let (buf, _) = self.buf.split_at(self.len());
+
+		// SAFETY: Archived strings are either checked or
+		// trusted to only contain valid UTF-8.
+		unsafe { str::from_utf8_unchecked(buf) }
+	}
+}
+
+// SAFETY: Every octet of the length and buffer is
+// checked.
+unsafe impl<C, const N: usize> CheckBytes<C> for ArchivedString<N>
+where
+	C:        Fallible + ?Sized,
+	C::Error: Source,
+{
+	#[inline]
+	unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+		// SAFETY: The caller guarantees that `value` is
+		// aligned and points to enough octets.
+		unsafe { ArchivedUsize::check_bytes(&raw const (*value).len, context)? };
+
+		// SAFETY: All fields are valid for any bit pattern.
+		let value = unsafe { &*value };
+
+		let len = value.len();
+
+		if len > N {
+			return Err(Source::new(LengthError {
+				remaining: N,
+				count:     len,
+			}));
+		}
+
+		let (buf, _) = value.buf.split_at(len);
+
+		if let Err(e) = str::from_utf8(buf) {
+			let i = e.valid_up_to();
+			let c = buf[i];
+
+			return Err(Source::new(Utf8Error { value: c, index: i }));
+		}
+
+		Ok(())
+	}
+}
+
+impl<const N: usize> Debug for ArchivedString<N> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(self.as_str(), f)
+	}
+}
+
+impl<const N: usize> Deref for ArchivedString<N> {
+	type Target = str;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		self.as_str()
+	}
+}
+
+impl<const N: usize> Display for ArchivedString<N> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_str(), f)
+	}
+}
+
+impl<const N: usize> PartialEq<str> for ArchivedString<N> {
+	#[inline(always)]
+	fn eq(&self, other: &str) -> bool {
+		self.as_str() == other
+	}
+}
+
+impl<const N: usize> PartialEq<&str> for ArchivedString<N> {
+	#[inline(always)]
+	fn eq(&self, other: &&str) -> bool {
+		self.as_str() == *other
+	}
+}
+
+// SAFETY: `ArchivedString` is `repr(C)` and only
+// contains portable fields.
+unsafe impl<const N: usize> Portable for ArchivedString<N> { }
+
+#[cfg_attr(doc, doc(cfg(feature = "rkyv")))]
+impl<const N: usize> Archive for String<N> {
+	type Archived = ArchivedString<N>;
+	type Resolver = ();
+
+	#[inline]
+	fn resolve(&self, _resolver: Self::Resolver, out: Place<Self::Archived>) {
+		let len = ArchivedUsize::from_native(self.len() as FixedUsize);
+
+		// NOTE: Octets past the length are always initial-
+		// ised, but we zero them to avoid leaking old data.
+		let mut buf = [0x00; N];
+		buf[..self.len()].copy_from_slice(self.as_bytes());
+
+		// SAFETY: We only write fully-initialised fields,
+		// leaving the (already initialised) padding as is.
+		unsafe {
+			let out = out.ptr();
+
+			(&raw mut (*out).len).write(len);
+			(&raw mut (*out).buf).write(buf);
+		}
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "rkyv")))]
+impl<D: Fallible + ?Sized, const N: usize> Deserialize<String<N>, D> for ArchivedString<N> {
+	#[inline]
+	fn deserialize(&self, _deserializer: &mut D) -> Result<String<N>, D::Error> {
+		// SAFETY: The archived string contains at most `N`
+		// octets.
+		let this = unsafe { String::from_str_unchecked(self.as_str()) };
+		Ok(this)
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "rkyv")))]
+impl<S: Fallible + ?Sized, const N: usize> Serialize<S> for String<N> {
+	#[inline(always)]
+	fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+		Ok(())
+	}
+}
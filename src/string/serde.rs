@@ -3,12 +3,16 @@
 #![cfg(feature = "serde")]
 
 use crate::String;
+use crate::error::{LengthError, StringError};
 
 use core::fmt::{self, Formatter};
 use core::marker::PhantomData;
 use serde::de::{self, Deserialize, Deserializer, Visitor};
 use serde::ser::{Serialize, Serializer};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[derive(Debug, Default)]
 struct StringVisitor<const N: usize> {
 	_s: PhantomData<fn() -> String<N>>,
@@ -22,6 +26,19 @@ impl<const N: usize> StringVisitor<N> {
 			_s: PhantomData,
 		}
 	}
+
+	/// Describes a string that is too long for the visitor.
+	///
+	/// The message includes both the length of the string and the capacity `N`, e.g.:
+	///
+	/// ```text
+	/// string of 20 octets exceeds capacity 16
+	/// ```
+	#[inline]
+	#[must_use]
+	fn length_error<E: de::Error>(e: &LengthError) -> E {
+		E::custom(format_args!("string of {} octets exceeds capacity {N}", e.count))
+	}
 }
 
 impl<'de, const N: usize> Visitor<'de> for StringVisitor<N> {
@@ -34,7 +51,38 @@ impl<'de, const N: usize> Visitor<'de> for StringVisitor<N> {
 
 	#[inline]
 	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
-		String::from_str(v).map_err(E::custom)
+		String::from_str(v).map_err(|e| Self::length_error(&e))
+	}
+
+	#[inline]
+	fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+		self.visit_str(v)
+	}
+
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn visit_string<E: de::Error>(self, v: alloc::string::String) -> Result<Self::Value, E> {
+		self.visit_str(&v)
+	}
+
+	#[inline]
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		String::try_from(v).map_err(|e| match e {
+			StringError::Length(e) => Self::length_error(&e),
+
+			e => E::custom(e),
+		})
+	}
+
+	#[inline]
+	fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+		self.visit_bytes(v)
+	}
+
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		self.visit_bytes(&v)
 	}
 }
 
@@ -0,0 +1,163 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "oct")]
+
+use crate::String;
+use crate::error::{LengthError, Utf8Error};
+
+use core::ops::{Deref, DerefMut};
+use core::str;
+use oct::decode::{self, Decode};
+use oct::encode::{self, Encode, SizedEncode};
+use oct::error::CollectionDecodeError;
+
+/// Short-prefix encoding adapter for [`String`].
+///
+/// Contrary to [`String`] itself, this type encodes its length as a single [`u8`] instead of a [`usize`].
+/// This reduces the overhead of each string to one octet, but limits the capacity to at most `255` octets.
+///
+/// Using this type with an `N` that is greater than `255` results in a compile-time error:
+///
+/// ```rust,compile_fail
+/// use conststr::ShortString;
+///
+/// let _ = ShortString::<0x100>::default();
+/// ```
+///
+/// ```rust,compile_fail
+/// use conststr::ShortString;
+/// use oct::encode::SizedEncode;
+///
+/// let _ = ShortString::<0x100>::MAX_ENCODED_SIZE;
+/// ```
+#[cfg_attr(doc, doc(cfg(feature = "oct")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct ShortString<const N: usize>(String<N>);
+
+impl<const N: usize> ShortString<N> {
+	/// Wraps a string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(s: String<N>) -> Self {
+		const { assert!(N <= 0xFF, "cannot use short string with capacity greater than 255") };
+
+		Self(s)
+	}
+
+	/// Unwraps the string.
+	#[inline(always)]
+	#[must_use]
+	pub const fn into_inner(self) -> String<N> {
+		self.0
+	}
+}
+
+impl<const N: usize> Decode for ShortString<N> {
+	type Error = CollectionDecodeError<LengthError, Utf8Error>;
+
+	#[inline]
+	#[track_caller]
+	fn decode(input: &mut decode::Input) -> Result<Self, Self::Error> {
+		let Ok(len) = u8::decode(input);
+		let len = usize::from(len);
+
+		if len > N {
+			return Err(CollectionDecodeError::BadLength(
+				LengthError {
+					remaining: N,
+					count:     len,
+				}
+			));
+		}
+
+		let mut buf = [0x00; N];
+		input.read_into(&mut buf[..len]);
+
+		if let Err(e) = str::from_utf8(&buf[..len]) {
+			let i = e.valid_up_to();
+			let c = buf[i];
+
+			return Err(CollectionDecodeError::BadItem(
+				Utf8Error {
+					value: c,
+					index: i,
+				},
+			));
+		}
+
+		// SAFETY: We have tested that the first `len`
+		// octets are valid UTF-8. The remaining, if any,
+		// are null and therefore also valid.
+		let s = unsafe { String::from_raw_parts(buf, len) };
+		Ok(Self::new(s))
+	}
+}
+
+impl<const N: usize> Default for ShortString<N> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new(String::new())
+	}
+}
+
+impl<const N: usize> Deref for ShortString<N> {
+	type Target = String<N>;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<const N: usize> DerefMut for ShortString<N> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<const N: usize> Encode for ShortString<N> {
+	type Error = <u8 as Encode>::Error;
+
+	/// Encodes the string with a single-octet length prefix.
+	#[inline]
+	#[track_caller]
+	fn encode(&self, output: &mut encode::Output) -> Result<(), Self::Error> {
+		const { assert!(N <= 0xFF, "cannot use short string with capacity greater than 255") };
+
+		// NOTE: The capacity has been asserted to be at
+		// most `255` above, so the length always fits.
+		let len = self.len() as u8;
+		len.encode(output)?;
+
+		for octet in self.as_bytes() {
+			octet.encode(output)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<const N: usize> From<String<N>> for ShortString<N> {
+	#[inline(always)]
+	fn from(value: String<N>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<const N: usize> From<ShortString<N>> for String<N> {
+	#[inline(always)]
+	fn from(value: ShortString<N>) -> Self {
+		value.0
+	}
+}
+
+impl<const N: usize> SizedEncode for ShortString<N> {
+	const MAX_ENCODED_SIZE: usize = {
+		assert!(N <= 0xFF, "cannot use short string with capacity greater than 255");
+
+		u8::MAX_ENCODED_SIZE
+		+ u8::MAX_ENCODED_SIZE * N
+	};
+}
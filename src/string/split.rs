@@ -0,0 +1,89 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::String;
+use crate::utf8::utf8_char_len;
+
+use core::iter::FusedIterator;
+
+/// Iterator over the parts of a string separated by a delimiter.
+///
+/// This iterator is returned by [`String::split_str`].
+/// Parts are split as per [`str::split`], including leading and trailing empty parts.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Split<'a, const N: usize> {
+	string: &'a String<N>,
+	delim:  &'a str,
+
+	start:    usize,
+	position: usize,
+	finished: bool,
+}
+
+impl<'a, const N: usize> Split<'a, N> {
+	#[inline(always)]
+	pub(super) const fn new(string: &'a String<N>, delim: &'a str) -> Self {
+		Self {
+			string,
+			delim,
+
+			start:    0x0,
+			position: 0x0,
+			finished: false,
+		}
+	}
+
+	/// Borrows the part of the string between `start` and `end`.
+	#[inline]
+	#[must_use]
+	const fn part(&self, start: usize, end: usize) -> &'a str {
+		let (s, _) = self.string.as_str().split_at(end);
+		let (_, s) = s.split_at(start);
+
+		s
+	}
+}
+
+impl<const N: usize> FusedIterator for Split<'_, N> { }
+
+impl<'a, const N: usize> Iterator for Split<'a, N> {
+	type Item = &'a str;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+
+		let len = self.string.len();
+
+		let mut index = self.position;
+		while index + self.delim.len() <= len {
+			if self.string.matches_at(index, self.delim) {
+				let part = self.part(self.start, index);
+
+				self.start = index + self.delim.len();
+
+				// NOTE: Empty delimiters match at every bound-
+				// ary, so we have to skip ahead by a whole
+				// character to avoid matching indefinitely.
+				self.position = if !self.delim.is_empty() {
+					self.start
+				} else if index < len {
+					index + utf8_char_len(self.string.as_bytes()[index])
+				} else {
+					index + 0x1
+				};
+
+				return Some(part);
+			}
+
+			index += 0x1;
+		}
+
+		self.finished = true;
+
+		let part = self.part(self.start, len);
+		Some(part)
+	}
+}
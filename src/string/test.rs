@@ -3,9 +3,12 @@
 #![cfg(test)]
 
 use core::cmp::Ordering;
-use conststr::{String, string};
-use conststr::error::{LengthError, Utf8Error};
+use core::str;
+use core::time::Duration;
+use conststr::{String, const_concat, format_into, match_string, max_capacity, min_capacity, string};
+use conststr::error::{CStrError, LengthError, ParseError, StringError, Utf8Error};
 use oct::decode::{Decode, Input};
+use oct::encode::{Encode, Output, SizedEncode};
 
 #[test]
 fn test_string() {
@@ -27,6 +30,27 @@ fn test_string_decode() {
 	);
 }
 
+#[test]
+fn test_string_decode_octets() {
+	let data: &[u8] = b"if constexpr";
+
+	let mut buf = [0x00; 0x20];
+
+	let mut output = Output::new(&mut buf);
+	data.encode(&mut output).unwrap();
+
+	let mut input = Input::new(&buf);
+	assert_eq!(String::<0x10>::decode(&mut input).unwrap(), "if constexpr");
+
+	let data: &[u8] = b"a\xFF";
+
+	let mut output = Output::new(&mut buf);
+	data.encode(&mut output).unwrap();
+
+	let mut input = Input::new(&buf);
+	let _ = String::<0x10>::decode(&mut input).unwrap_err();
+}
+
 #[test]
 fn test_string_macro() {
 	let s0: String<0x08> = string!("conststr");
@@ -180,3 +204,2571 @@ fn test_string_remove_non_boundary() {
 
 	let _ = s.remove(0x2);
 }
+
+#[test]
+fn test_string_expand_tabs() {
+	let s: String<0x8> = string!("a\tb");
+
+	assert_eq!(s.expand_tabs::<0x8>(0x4), Ok(string!("a   b")));
+	assert_eq!(s.expand_tabs::<0x8>(0x1), Ok(string!("a b")));
+
+	let s: String<0x8> = string!("\tab\n\tc");
+
+	assert_eq!(s.expand_tabs::<0x10>(0x2), Ok(string!("  ab\n  c")));
+
+	assert_eq!(
+		s.expand_tabs::<0x4>(0x2),
+		Err(LengthError { remaining: 0x0, count: 0x1 }),
+	);
+}
+
+#[test]
+fn test_string_try_from_iter() {
+	assert_eq!(
+		String::<0x4>::try_from_iter("hell".chars()),
+		Ok(string!("hell")),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from_iter("hello".chars()),
+		Err(LengthError { remaining: 0x0, count: 0x1 }),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from_iter("ab\u{00F1}".chars()),
+		Ok(string!("ab\u{00F1}")),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from_iter("abc\u{00F1}".chars()),
+		Err(LengthError { remaining: 0x1, count: 0x2 }),
+	);
+}
+
+#[test]
+fn test_string_parse_int_list() {
+	let s: String<0x8> = string!("1,2,3");
+
+	assert_eq!(s.parse_int_list::<0x4>(','), Ok(([0x1, 0x2, 0x3, 0x0], 0x3)));
+	assert_eq!(s.parse_int_list::<0x3>(','), Ok(([0x1, 0x2, 0x3], 0x3)));
+
+	assert_eq!(
+		s.parse_int_list::<0x2>(','),
+		Err(ParseError::SmallBuffer(LengthError { remaining: 0x2, count: 0x3 })),
+	);
+
+	let s: String<0x8> = string!("-4;x;6");
+
+	assert!(matches!(s.parse_int_list::<0x4>(';'), Err(ParseError::BadField(..))));
+
+	let s: String<0x8> = string!("");
+
+	assert_eq!(s.parse_int_list::<0x4>(','), Ok(([0x0; 0x4], 0x0)));
+}
+
+#[test]
+fn test_string_try_from_bytes() {
+	assert_eq!(
+		String::<0x4>::try_from(b"abc".as_slice()),
+		Ok(string!("abc")),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from(b"hello".as_slice()),
+		Err(StringError::Length(LengthError { remaining: 0x4, count: 0x5 })),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from(b"a\xFFc".as_slice()),
+		Err(StringError::Utf8(Utf8Error { value: 0xFF, index: 0x1 })),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from(*b"ab"),
+		Ok(string!("ab")),
+	);
+
+	assert_eq!(
+		String::<0x4>::try_from(*b"\xC3("),
+		Err(Utf8Error { value: 0xC3, index: 0x0 }),
+	);
+}
+
+#[test]
+fn test_string_normalize_spaces() {
+	let mut s: String<0x10> = string!("  a   b  ");
+
+	s.normalize_spaces();
+	assert_eq!(s, "a b");
+
+	let mut s: String<0x10> = string!("\tfoo\n\r bar\u{00A0}");
+
+	s.normalize_spaces();
+	assert_eq!(s, "foo bar\u{00A0}");
+
+	let mut s: String<0x10> = string!(" \t ");
+
+	s.normalize_spaces();
+	assert_eq!(s, "");
+}
+
+#[test]
+fn test_string_eq_char() {
+	let s: String<0x4> = string!("\u{00E9}");
+
+	assert_eq!(s,          '\u{00E9}');
+	assert_eq!('\u{00E9}', s);
+
+	assert_ne!(s, 'e');
+
+	let s: String<0x4> = string!("ab");
+
+	assert_ne!(s,   'a');
+	assert_ne!('a', s);
+
+	let s: String<0x4> = string!("");
+
+	assert_ne!(s, '\0');
+}
+
+#[test]
+fn test_string_is_valid_email_localpart() {
+	const {
+		macro_rules! test {
+			($s:literal, $result:literal$(,)?) => {{
+				let s: String<0x10> = string!($s);
+				assert!(s.is_valid_email_localpart() == $result);
+			}};
+		}
+
+		test!("john.doe",     true);
+		test!("a+b_c{d}",     true);
+		test!(".john",        false);
+		test!("john.",        false);
+		test!("jo..hn",       false);
+		test!("jo hn",        false);
+		test!("j\u{00F6}hn", false);
+		test!("",             false);
+	}
+}
+
+#[test]
+fn test_string_shell_escape() {
+	let s: String<0x8> = string!("a b");
+	assert_eq!(s.shell_escape::<0x8>(), Ok(string!("'a b'")));
+
+	let s: String<0x8> = string!("it's");
+	assert_eq!(s.shell_escape::<0x10>(), Ok(string!("'it'\\''s'")));
+
+	let s: String<0x8> = string!("a-b/c.d");
+	assert_eq!(s.shell_escape::<0x8>(), Ok(string!("a-b/c.d")));
+
+	let s: String<0x8> = string!("");
+	assert_eq!(s.shell_escape::<0x8>(), Ok(string!("''")));
+
+	let s: String<0x8> = string!("$HOME");
+	assert_eq!(
+		s.shell_escape::<0x4>(),
+		Err(LengthError { remaining: 0x0, count: 0x1 }),
+	);
+}
+
+#[test]
+fn test_string_eq_ignore_ascii_case() {
+	const {
+		let s: String<0x8> = string!("HELLO");
+
+		assert!( s.eq_ignore_ascii_case("hello"));
+		assert!( s.eq_ignore_ascii_case("HeLlO"));
+		assert!(!s.eq_ignore_ascii_case("hell"));
+		assert!(!s.eq_ignore_ascii_case("hellO!"));
+
+		let s: String<0x8> = string!("STRA\u{00DF}E");
+
+		assert!( s.eq_ignore_ascii_case("stra\u{00DF}e"));
+		assert!(!s.eq_ignore_ascii_case("stra\u{00E3}e"));
+		assert!(!s.eq_ignore_ascii_case("strasse"));
+	}
+}
+
+#[test]
+fn test_string_parse_duration() {
+	macro_rules! test {
+		($s:literal, $result:expr$(,)?) => {{
+			let s: String<0x10> = string!($s);
+			assert_eq!(s.parse_duration(), $result);
+		}};
+	}
+
+	test!("500ms", Some(Duration::from_millis(0x1F4)));
+	test!("3s",    Some(Duration::from_secs(0x3)));
+	test!("2m",    Some(Duration::from_secs(0x78)));
+	test!("1h",    Some(Duration::from_secs(0xE10)));
+	test!("7us",   Some(Duration::from_micros(0x7)));
+	test!("9ns",   Some(Duration::from_nanos(0x9)));
+	test!("bad",   None);
+	test!("ms",    None);
+	test!("10",    None);
+	test!("10d",   None);
+	test!("1h30m", None);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_string_arbitrary() {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	let data = [
+		0x41, 0x00, 0x00, 0x00, // 'A'
+		0xF1, 0x00, 0x00, 0x00, // '\u{00F1}'
+		0x80, 0xF4, 0x01, 0x00, // '\u{1F480}'
+		0x42, 0x00, 0x00, 0x00, // 'B'
+		0x10,                   // Length.
+	];
+
+	let mut u = Unstructured::new(&data);
+
+	let s = String::<0x6>::arbitrary(&mut u).unwrap();
+
+	assert_eq!(s, "A\u{00F1}");
+	assert!(s.len() <= 0x6);
+
+	let _ = core::str::from_utf8(s.as_bytes()).unwrap();
+}
+
+#[test]
+fn test_string_numbers() {
+	let s: String<0x10> = string!("a12b345");
+
+	let mut numbers = s.numbers();
+
+	assert_eq!(numbers.next(), Some(0xC));
+	assert_eq!(numbers.next(), Some(0x159));
+	assert_eq!(numbers.next(), None);
+
+	let s: String<0x20> = string!("99999999999999999999\u{00B2}7");
+
+	let mut numbers = s.numbers();
+
+	assert_eq!(numbers.next(), Some(u64::MAX));
+	assert_eq!(numbers.next(), Some(0x7));
+	assert_eq!(numbers.next(), None);
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn test_string_defmt() {
+	const fn assert_format<T: defmt::Format>() { }
+
+	assert_format::<String<0x0>>();
+	assert_format::<String<0x10>>();
+}
+
+#[test]
+fn test_string_eq_exact() {
+	let s0: String<0x4> = string!("a");
+	let s1: String<0x8> = string!("a");
+	let s2: String<0x4> = string!("a");
+	let s3: String<0x4> = string!("b");
+
+	assert_eq!(s0, s1);
+
+	assert!(!s0.eq_exact(&s1));
+	assert!( s0.eq_exact(&s2));
+	assert!(!s0.eq_exact(&s3));
+}
+
+#[cfg(feature = "ufmt")]
+#[test]
+fn test_string_ufmt() {
+	use ufmt::uwrite;
+
+	let mut s = String::<0x10>::new();
+
+	uwrite!(s, "{}", 0x7FFF_FFFFu32).unwrap();
+	assert_eq!(s, "2147483647");
+
+	let mut t = String::<0x10>::new();
+
+	uwrite!(t, "[{}]", s).unwrap();
+	assert_eq!(t, "[2147483647]");
+
+	assert_eq!(
+		uwrite!(t, "{}", s),
+		Err(LengthError { remaining: 0x4, count: 0xA }),
+	);
+}
+
+#[test]
+fn test_string_rolling_hashes() {
+	let s: String<0x10> = string!("abcXabcabc");
+
+	let mut hashes = [0x0; 0x8];
+	for (hash, value) in hashes.iter_mut().zip(s.rolling_hashes(0x3)) {
+		*hash = value;
+	}
+
+	assert_eq!(s.rolling_hashes(0x3).count(), 0x8);
+
+	assert_eq!(hashes[0x0], 0x0061_6263);
+	assert_eq!(hashes[0x0], hashes[0x4]);
+	assert_eq!(hashes[0x0], hashes[0x7]);
+	assert_ne!(hashes[0x0], hashes[0x1]);
+
+	let s: String<0x10> = string!("aaaaaaaaaaaa");
+
+	assert!(s.rolling_hashes(0x5).all(|hash| hash == 0x0EA3_81F3));
+
+	let s: String<0x10> = string!("ab");
+
+	assert_eq!(s.rolling_hashes(0x3).next(), None);
+}
+
+#[test]
+fn test_string_to_ascii_lossy() {
+	let s: String<0x8> = string!("a\u{20AC}b");
+
+	assert_eq!(s.to_ascii_lossy::<0x8>(), "a?b");
+	assert_eq!(s.to_ascii_lossy::<0x2>(), "a?");
+
+	let s: String<0x8> = string!("\u{1F480}\u{00F1}");
+
+	assert_eq!(s.to_ascii_lossy::<0x8>(), "??");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_string_deserialize() {
+	use serde::de::Deserialize;
+	use serde::de::value::{BorrowedStrDeserializer, BytesDeserializer, Error};
+
+	let s = String::<0x8>::deserialize(BytesDeserializer::<Error>::new(b"conststr")).unwrap();
+	assert_eq!(s, "conststr");
+
+	let s = String::<0x8>::deserialize(BorrowedStrDeserializer::<Error>::new("\u{1F480}")).unwrap();
+	assert_eq!(s, "\u{1F480}");
+
+	let _ = String::<0x8>::deserialize(BytesDeserializer::<Error>::new(b"a\xFF")).unwrap_err();
+	let _ = String::<0x4>::deserialize(BytesDeserializer::<Error>::new(b"conststr")).unwrap_err();
+	let _ = String::<0x4>::deserialize(BorrowedStrDeserializer::<Error>::new("conststr")).unwrap_err();
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+#[test]
+fn test_string_deserialize_too_long() {
+	use alloc::string::ToString;
+	use serde::de::Deserialize;
+	use serde::de::value::{BorrowedStrDeserializer, BytesDeserializer, Error};
+
+	let e = String::<0x4>::deserialize(BorrowedStrDeserializer::<Error>::new("conststr")).unwrap_err();
+	assert_eq!(e.to_string(), "string of 8 octets exceeds capacity 4");
+
+	let e = String::<0x10>::deserialize(BorrowedStrDeserializer::<Error>::new("constant string, \u{00E6}")).unwrap_err();
+	assert_eq!(e.to_string(), "string of 19 octets exceeds capacity 16");
+
+	let e = String::<0x4>::deserialize(BytesDeserializer::<Error>::new(b"conststr")).unwrap_err();
+	assert_eq!(e.to_string(), "string of 8 octets exceeds capacity 4");
+}
+
+#[test]
+fn test_string_resize() {
+	let s: String<0x10> = string!("conststr");
+
+	assert_eq!(s.resize::<0x8>(), Ok(string!("conststr")));
+	assert_eq!(s.resize::<0x7>(), Err(LengthError { remaining: 0x7, count: 0x8 }));
+
+	const {
+		let s: String<0x4> = string!("abc");
+		let s: String<0x8> = s.grow();
+
+		assert!(s.len() == 0x3);
+		assert!(matches!(s.as_bytes(), b"abc"));
+	}
+}
+
+#[test]
+fn test_string_prefix_function() {
+	let s: String<0x6> = string!("abcabc");
+	assert_eq!(s.prefix_function::<0x6>(), [0x0, 0x0, 0x0, 0x1, 0x2, 0x3]);
+
+	let s: String<0x8> = string!("aabaaab");
+	assert_eq!(s.prefix_function::<0x8>(), [0x0, 0x1, 0x0, 0x1, 0x2, 0x2, 0x3, 0x0]);
+
+	let s: String<0x4> = string!("");
+	assert_eq!(s.prefix_function::<0x4>(), [0x0; 0x4]);
+}
+
+#[test]
+fn test_string_from_char_fn() {
+	let generate = |i: usize| (i < 0x4).then_some('a');
+
+	assert_eq!(String::<0x4>::from_char_fn(generate), Ok(string!("aaaa")));
+	assert_eq!(String::<0x3>::from_char_fn(generate), Err(LengthError { remaining: 0x0, count: 0x1 }));
+
+	let e = String::<0x8>::from_char_fn(|i| char::from_digit(u32::try_from(i).ok()?, 0xA)).unwrap_err();
+	assert_eq!(e, LengthError { remaining: 0x0, count: 0x1 });
+}
+
+#[test]
+fn test_string_is_rotation_of() {
+	let s: String<0x8> = string!("abcde");
+
+	assert!(String::<0x8>::from_str("cdeab").unwrap().is_rotation_of(&s));
+	assert!(String::<0x8>::from_str("abcde").unwrap().is_rotation_of(&s));
+	assert!(!String::<0x8>::from_str("abced").unwrap().is_rotation_of(&s));
+	assert!(!String::<0x8>::from_str("abcd").unwrap().is_rotation_of(&s));
+
+	let s: String<0x8> = string!("\u{00E6}bc");
+
+	assert!(String::<0x8>::from_str("bc\u{00E6}").unwrap().is_rotation_of(&s));
+	assert!(!String::<0x8>::from_str("b\u{00E6}c").unwrap().is_rotation_of(&s));
+
+	let s: String<0x0> = string!("");
+	assert!(String::<0x8>::from_str("").unwrap().is_rotation_of(&s));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_debug_hex() {
+	use alloc::format;
+
+	let s: String<0x8> = string!("AB");
+	assert_eq!(format!("{}", s.debug_hex()), "41 42");
+
+	let s: String<0x8> = string!("\u{00E6}\n");
+	assert_eq!(format!("{}", s.debug_hex()), "C3 A6 0A");
+
+	let s: String<0x8> = string!("");
+	assert_eq!(format!("{}", s.debug_hex()), "");
+}
+
+#[test]
+fn test_string_find() {
+	const {
+		let s: String<0x10> = string!("key=a\u{00E6}b=c");
+
+		assert!(matches!(s.find_str("key"), Some(0x0)));
+		assert!(matches!(s.find_str("=c"),  Some(0x8)));
+		assert!(s.find_str("=d").is_none());
+		assert!(matches!(s.find_str(""),    Some(0x0)));
+
+		assert!(matches!(s.find_str("\u{00E6}"), Some(0x5)));
+		assert!(matches!(s.find_char('\u{00E6}'), Some(0x5)));
+
+		assert!(matches!(s.find_char('='), Some(0x3)));
+		assert!(matches!(s.find_char('k'), Some(0x0)));
+		assert!(matches!(s.find_char('c'), Some(0x9)));
+		assert!(s.find_char('\u{00C6}').is_none());
+
+		assert!(matches!(s.rfind_str("="),   Some(0x8)));
+		assert!(matches!(s.rfind_str("key"), Some(0x0)));
+		assert!(s.rfind_str("=d").is_none());
+		assert!(matches!(s.rfind_str(""),    Some(0xA)));
+
+		assert!(matches!(s.rfind_char('='), Some(0x8)));
+		assert!(matches!(s.rfind_char('\u{00E6}'), Some(0x5)));
+		assert!(s.rfind_char('x').is_none());
+
+		let s: String<0x4> = string!("");
+
+		assert!(matches!(s.find_str(""),   Some(0x0)));
+		assert!(matches!(s.rfind_str(""),  Some(0x0)));
+		assert!(s.find_char('a').is_none());
+	}
+}
+
+#[allow(clippy::approx_constant)]
+#[test]
+fn test_string_push_f32_fixed() {
+	let mut s = String::<0x10>::new();
+
+	s.push_f32_fixed(3.141_59, 0x2).unwrap();
+	assert_eq!(s, "3.14");
+
+	s.clear();
+	s.push_f32_fixed(-2.5, 0x0).unwrap();
+	assert_eq!(s, "-3");
+
+	s.clear();
+	s.push_f32_fixed(0.996, 0x2).unwrap();
+	assert_eq!(s, "1.00");
+
+	s.clear();
+	s.push_f32_fixed(0.062_5, 0x3).unwrap();
+	assert_eq!(s, "0.063");
+
+	s.clear();
+	s.push_f32_fixed(1.5, 0x4).unwrap();
+	assert_eq!(s, "1.5000");
+
+	s.clear();
+	s.push_f32_fixed(f32::NEG_INFINITY, 0x2).unwrap();
+	assert_eq!(s, "-inf");
+
+	s.clear();
+	s.push_f32_fixed(f32::NAN, 0x2).unwrap();
+	assert_eq!(s, "NaN");
+
+	let mut s: String<0x4> = string!("x=");
+
+	assert_eq!(s.push_f32_fixed(12.5, 0x1), Err(LengthError { remaining: 0x0, count: 0x1 }));
+	assert_eq!(s, "x=");
+}
+
+#[test]
+fn test_string_split_once() {
+	const {
+		let s: String<0x8> = string!("a=b=c");
+
+		let Some((head, tail)) = s.split_once("=") else { panic!() };
+		assert!(matches!(head.as_bytes(), b"a"));
+		assert!(matches!(tail.as_bytes(), b"b=c"));
+
+		let Some((head, tail)) = s.rsplit_once("=") else { panic!() };
+		assert!(matches!(head.as_bytes(), b"a=b"));
+		assert!(matches!(tail.as_bytes(), b"c"));
+
+		let Some((head, tail)) = s.split_once("b=") else { panic!() };
+		assert!(matches!(head.as_bytes(), b"a="));
+		assert!(matches!(tail.as_bytes(), b"c"));
+
+		assert!(s.split_once(":").is_none());
+		assert!(s.rsplit_once(":").is_none());
+	}
+}
+
+#[test]
+fn test_string_lines_into() {
+	let s: String<0x30> = string!("GET /\r\nHost: example.com\nAccept: */*\n");
+
+	let mut lines = s.lines_into::<0xC>();
+
+	assert_eq!(lines.next(), Some(Ok(string!("GET /"))));
+	assert_eq!(lines.next(), Some(Err(LengthError { remaining: 0xC, count: 0x11 })));
+	assert_eq!(lines.next(), Some(Ok(string!("Accept: */*"))));
+	assert_eq!(lines.next(), None);
+}
+
+#[test]
+fn test_string_strip_str() {
+	const {
+		let s: String<0x10> = string!("key=value");
+
+		let Some(tail) = s.strip_prefix_str("key=") else { panic!() };
+		assert!(matches!(tail.as_bytes(), b"value"));
+
+		let Some(head) = s.strip_suffix_str("=value") else { panic!() };
+		assert!(matches!(head.as_bytes(), b"key"));
+
+		let Some(tail) = s.strip_prefix_str("") else { panic!() };
+		assert!(matches!(tail.as_bytes(), b"key=value"));
+
+		assert!(s.strip_prefix_str("value").is_none());
+		assert!(s.strip_suffix_str("key").is_none());
+
+		assert!(s.strip_prefix_str("key=value=").is_none());
+		assert!(s.strip_suffix_str("=key=value").is_none());
+	}
+}
+
+#[test]
+fn test_string_consume_prefix() {
+	let mut s: String<0x10> = string!("GET /path");
+
+	assert!(s.consume_prefix("GET "));
+	assert_eq!(s, "/path");
+
+	assert!(!s.consume_prefix("GET "));
+	assert_eq!(s, "/path");
+
+	assert!(!s.consume_prefix("/path/"));
+	assert_eq!(s, "/path");
+
+	assert!(s.consume_prefix("/path"));
+	assert_eq!(s, "");
+}
+
+#[test]
+fn test_string_remove_checked() {
+	let mut s: String<0x8> = string!("a\u{00E6}b");
+
+	assert_eq!(s.remove_checked(0x2), None);
+	assert_eq!(s.remove_checked(0x4), None);
+	assert_eq!(s.remove_checked(0x9), None);
+	assert_eq!(s.as_bytes(), b"a\xC3\xA6b");
+
+	assert_eq!(s.remove_checked(0x1), Some('\u{00E6}'));
+	assert_eq!(s, "ab");
+
+	assert_eq!(s.remove_checked(0x1), Some('b'));
+	assert_eq!(s.remove_checked(0x0), Some('a'));
+	assert_eq!(s.remove_checked(0x0), None);
+	assert_eq!(s, "");
+}
+
+#[test]
+fn test_string_to_canonical_host() {
+	let s: String<0x10> = string!("Example.COM.");
+	assert_eq!(s.to_canonical_host::<0xB>(), Ok(string!("example.com")));
+
+	let s: String<0x10> = string!("Example.COM");
+	assert_eq!(s.to_canonical_host::<0xB>(), Ok(string!("example.com")));
+
+	let s: String<0x10> = string!("Example.COM..");
+	assert_eq!(s.to_canonical_host::<0xB>(), Err(LengthError { remaining: 0xB, count: 0xC }));
+}
+
+#[test]
+fn test_string_truncate_chars() {
+	const {
+		let mut s: String<0x10> = string!("\u{00E6}b\u{1F480}d");
+
+		s.truncate_chars(0x8);
+		assert!(matches!(s.as_bytes(), b"\xC3\xA6b\xF0\x9F\x92\x80d"));
+
+		s.truncate_chars(0x3);
+		assert!(matches!(s.as_bytes(), b"\xC3\xA6b\xF0\x9F\x92\x80"));
+
+		s.truncate_chars(0x2);
+		assert!(matches!(s.as_bytes(), b"\xC3\xA6b"));
+
+		s.truncate_chars(0x0);
+		assert!(s.is_empty());
+	}
+}
+
+#[test]
+fn test_string_display_chunks() {
+	let s: String<0x8> = string!("abcdef");
+
+	let mut chunks = s.display_chunks(0x2);
+
+	assert_eq!(chunks.next(), Some("ab"));
+	assert_eq!(chunks.next(), Some("cd"));
+	assert_eq!(chunks.next(), Some("ef"));
+	assert_eq!(chunks.next(), None);
+
+	let s: String<0x8> = string!("a\u{00E6}\u{1F480}");
+
+	let mut chunks = s.display_chunks(0x3);
+
+	assert_eq!(chunks.next(), Some("a\u{00E6}"));
+	assert_eq!(chunks.next(), Some("\u{1F480}"));
+	assert_eq!(chunks.next(), None);
+}
+
+#[test]
+fn test_string_pop_front() {
+	const {
+		let mut s: String<0x8> = string!("a\u{00E6}\u{1F480}");
+
+		assert!(matches!(s.pop_front(), Some('a')));
+		assert!(matches!(s.pop_front(), Some('\u{00E6}')));
+		assert!(matches!(s.as_bytes(), b"\xF0\x9F\x92\x80"));
+		assert!(matches!(s.pop_front(), Some('\u{1F480}')));
+		assert!(s.pop_front().is_none());
+		assert!(s.is_empty());
+	}
+}
+
+#[test]
+fn test_string_matches_any_ignore_case() {
+	const OPTIONS: &[&str] = &["get", "post", "put"];
+
+	let s: String<0x8> = string!("POST");
+	assert_eq!(s.matches_any_ignore_case(OPTIONS), Some(0x1));
+
+	let s: String<0x8> = string!("Put");
+	assert_eq!(s.matches_any_ignore_case(OPTIONS), Some(0x2));
+
+	let s: String<0x8> = string!("PATCH");
+	assert_eq!(s.matches_any_ignore_case(OPTIONS), None);
+
+	let s: String<0x8> = string!("get");
+	assert_eq!(s.matches_any_ignore_case(&[]), None);
+}
+
+#[test]
+fn test_string_split_off() {
+	let mut s: String<0x8> = string!("a\u{00E6}bc");
+
+	assert_eq!(s.split_off(0x3), "bc");
+	assert_eq!(s, "a\u{00E6}");
+
+	assert_eq!(s.split_off(0x3), "");
+	assert_eq!(s, "a\u{00E6}");
+
+	assert_eq!(s.split_off(0x0), "a\u{00E6}");
+	assert_eq!(s, "");
+}
+
+#[test]
+#[should_panic]
+fn test_string_split_off_non_boundary() {
+	let mut s: String<0x4> = string!("\u{00E6}");
+
+	let _ = s.split_off(0x1);
+}
+
+#[test]
+fn test_string_reverse_dotted() {
+	let s: String<0x10> = string!("a.b.c");
+	assert_eq!(s.reverse_dotted::<0x5>(), Ok(string!("c.b.a")));
+
+	let s: String<0x10> = string!("www.example.com");
+	assert_eq!(s.reverse_dotted::<0x10>(), Ok(string!("com.example.www")));
+
+	let s: String<0x10> = string!("localhost");
+	assert_eq!(s.reverse_dotted::<0x10>(), Ok(string!("localhost")));
+
+	let s: String<0x10> = string!("a.b.");
+	assert_eq!(s.reverse_dotted::<0x10>(), Ok(string!(".b.a")));
+
+	let s: String<0x10> = string!("a.b.c");
+	assert_eq!(s.reverse_dotted::<0x4>(), Err(LengthError { remaining: 0x0, count: 0x1 }));
+}
+
+#[test]
+fn test_string_const_concat() {
+	const SEPARATOR: &str = "::";
+
+	let s = const_concat!(String<0x10>; "conststr", SEPARATOR, "String");
+	assert_eq!(s, "conststr::String");
+
+	let s = const_concat!(String<0x0>;);
+	assert_eq!(s, "");
+
+	const S: String<0x8> = const_concat!(String<0x8>; "a", "\u{00E6}", "b",);
+	assert_eq!(S, "a\u{00E6}b");
+}
+
+#[test]
+fn test_string_validate_limits() {
+	let s: String<0x10> = string!("\u{00E6}\u{00F8}\u{00E5}");
+
+	assert_eq!(s.validate_limits(0x6, 0x3), Ok(()));
+	assert_eq!(s.validate_limits(0x8, 0x2), Err(LengthError { remaining: 0x2, count: 0x3 }));
+	assert_eq!(s.validate_limits(0x4, 0x8), Err(LengthError { remaining: 0x4, count: 0x6 }));
+
+	const {
+		let s: String<0x10> = string!("abc");
+
+		assert!(matches!(s.validate_limits(0x3, 0x3), Ok(())));
+		assert!(matches!(s.validate_limits(0x2, 0x3), Err(LengthError { remaining: 0x2, count: 0x3 })));
+	}
+}
+
+#[test]
+fn test_string_repeat() {
+	let s: String<0x2> = string!("ab");
+	assert_eq!(s.repeat::<0x3, 0x6>(), "ababab");
+
+	let s: String<0x4> = string!("\u{00E6}");
+	assert_eq!(s.repeat::<0x2, 0x10>(), "\u{00E6}\u{00E6}");
+	assert_eq!(s.repeat::<0x0, 0x0>(), "");
+
+	const {
+		let s: String<0x2> = string!("-");
+		let s: String<0x8> = s.repeat::<0x4, 0x8>();
+
+		assert!(matches!(s.as_bytes(), b"----"));
+	}
+}
+
+#[test]
+fn test_string_push_str_if_absent() {
+	let mut s = String::<0x8>::new();
+
+	assert_eq!(s.push_str_if_absent(',', "a"), Ok(true));
+	assert_eq!(s.push_str_if_absent(',', "a"), Ok(false));
+	assert_eq!(s, "a");
+
+	assert_eq!(s.push_str_if_absent(',', "bc"), Ok(true));
+	assert_eq!(s.push_str_if_absent(',', "b"), Ok(true));
+	assert_eq!(s.push_str_if_absent(',', "bc"), Ok(false));
+	assert_eq!(s, "a,bc,b");
+
+	assert_eq!(s.push_str_if_absent(',', "de"), Err(LengthError { remaining: 0x1, count: 0x2 }));
+	assert_eq!(s, "a,bc,b");
+}
+
+#[test]
+fn test_string_hamming_distance() {
+	let s: String<0x8> = string!("karolin");
+
+	assert_eq!(s.hamming_distance(&String::<0x8>::from_str("kathrin").unwrap()), Some(0x3));
+	assert_eq!(s.hamming_distance(&String::<0x7>::from_str("karolin").unwrap()), Some(0x0));
+	assert_eq!(s.hamming_distance(&String::<0x8>::from_str("karoli").unwrap()),  None);
+	assert_eq!(s.hamming_distance(&String::<0x8>::from_str("karolina").unwrap()), None);
+
+	let s: String<0x8> = string!("\u{00E6}b");
+
+	assert_eq!(s.hamming_distance(&String::<0x8>::from_str("ab").unwrap()), Some(0x1));
+}
+
+#[test]
+fn test_string_ord_str() {
+	let s: String<0x8> = string!("mmm");
+
+	assert!(s < "zzz");
+	assert!(s > "aaa");
+	assert!(s > "mm");
+	assert!(s < "mmmm");
+	assert!(s <= "mmm");
+	assert!(s >= "mmm");
+
+	assert_eq!(s.partial_cmp("mmm"), Some(Ordering::Equal));
+	assert_eq!(s.partial_cmp(&"m"),  Some(Ordering::Greater));
+
+	assert!("zzz" > s);
+	assert!(*"aaa" < s);
+	assert!("mmm" == s);
+	assert!(*"mmm" == s);
+
+	#[cfg(feature = "alloc")]
+	{
+		let other = alloc::string::String::from("mmmm");
+
+		assert!(s < other);
+		assert!(other > s);
+	}
+}
+
+#[cfg(all(feature = "alloc", feature = "rkyv"))]
+#[test]
+fn test_string_rkyv() {
+	use conststr::ArchivedString;
+	use rkyv::rancor::Error;
+
+	let s: String<0x20> = string!("\u{1F480}conststr");
+
+	let data = rkyv::to_bytes::<Error>(&s).unwrap();
+
+	let archived = rkyv::access::<ArchivedString<0x20>, Error>(&data).unwrap();
+	assert_eq!(archived.as_str(), "\u{1F480}conststr");
+	assert_eq!(archived.len(), 0xC);
+
+	let other = rkyv::deserialize::<String<0x20>, Error>(archived).unwrap();
+	assert_eq!(other, s);
+
+	let mut data = data;
+	data[0x0] = 0xFF;
+
+	let _ = rkyv::access::<ArchivedString<0x20>, Error>(&data).unwrap_err();
+}
+
+#[test]
+fn test_string_from_i64() {
+	assert_eq!(String::<0x3>::from_i64(-0x2A), Ok(string!("-42")));
+	assert_eq!(String::<0x2>::from_i64(-0x2A), Err(LengthError { remaining: 0x2, count: 0x3 }));
+
+	assert_eq!(String::<0x1>::from_i64(0x0), Ok(string!("0")));
+	assert_eq!(String::<0x14>::from_i64(i64::MIN), Ok(string!("-9223372036854775808")));
+	assert_eq!(String::<0x14>::from_i64(i64::MAX), Ok(string!("9223372036854775807")));
+
+	const {
+		let Ok(s) = String::<0x8>::from_i64(0x3039) else { panic!() };
+		assert!(matches!(s.as_bytes(), b"12345"));
+	}
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn test_string_embedded_io() {
+	use embedded_io::{Error, ErrorKind, Write};
+
+	let data = "a\u{00E6}\u{1F480}".as_bytes();
+
+	// Feed the data one octet at a time, retrying
+	// incomplete characters once more octets are
+	// available.
+
+	let mut s = String::<0x8>::new();
+
+	let mut start = 0x0;
+	for end in 0x1..=data.len() {
+		match s.write(&data[start..end]) {
+			Ok(count) => start += count,
+
+			Err(e) => {
+				assert_eq!(e.kind(), ErrorKind::Interrupted);
+				assert_eq!(e, StringError::Incomplete(Utf8Error { value: data[start], index: 0x0 }));
+			}
+		}
+	}
+
+	assert_eq!(start, data.len());
+	assert_eq!(s, "a\u{00E6}\u{1F480}");
+
+	// Partial characters are never written.
+
+	let mut s = String::<0x4>::new();
+
+	assert_eq!(s.write(data), Ok(0x3));
+	assert_eq!(s, "a\u{00E6}");
+
+	assert_eq!(s.write(&data[0x3..]), Err(StringError::Length(LengthError { remaining: 0x1, count: 0x4 })));
+	assert_eq!(s, "a\u{00E6}");
+
+	assert_eq!(s.write(b"\xFFa"), Err(StringError::Utf8(Utf8Error { value: 0xFF, index: 0x0 })));
+	assert_eq!(s.write(b""), Ok(0x0));
+
+	// Incomplete sequences are distinguishable from in-
+	// valid data.
+
+	let mut s = String::<0x8>::new();
+
+	let incomplete = s.write(b"\xC3").unwrap_err();
+	let invalid    = s.write(b"\xFF").unwrap_err();
+
+	assert_eq!(incomplete, StringError::Incomplete(Utf8Error { value: 0xC3, index: 0x0 }));
+	assert_eq!(invalid,    StringError::Utf8(Utf8Error { value: 0xFF, index: 0x0 }));
+
+	assert_ne!(incomplete, invalid);
+	assert_ne!(incomplete.kind(), invalid.kind());
+
+	assert_eq!(incomplete.kind(), ErrorKind::Interrupted);
+	assert_eq!(invalid.kind(),    ErrorKind::InvalidData);
+
+	// A cut-off sequence followed by more data is in-
+	// valid and not incomplete.
+
+	assert_eq!(s.write(b"\xC3a"), Err(StringError::Utf8(Utf8Error { value: 0xC3, index: 0x0 })));
+
+	// Preceding characters are still written.
+
+	assert_eq!(s.write(b"a\xC3"), Ok(0x1));
+	assert_eq!(s, "a");
+
+	let mut s = String::<0x8>::new();
+
+	s.write_all(data).unwrap();
+	assert_eq!(s, "a\u{00E6}\u{1F480}");
+}
+
+#[test]
+fn test_string_leading_whitespace() {
+	const {
+		let s: String<0x10> = string!("    code");
+		assert!(matches!(s.leading_whitespace().as_bytes(), b"    "));
+
+		let s: String<0x10> = string!("\t \ncode  ");
+		assert!(matches!(s.leading_whitespace().as_bytes(), b"\t \n"));
+
+		let s: String<0x10> = string!("code");
+		assert!(s.leading_whitespace().is_empty());
+
+		let s: String<0x10> = string!("  ");
+		assert!(matches!(s.leading_whitespace().as_bytes(), b"  "));
+	}
+}
+
+#[test]
+fn test_string_escape() {
+	let s: String<0x10> = string!("a\n\u{0007}\u{00E6}\"");
+
+	assert_eq!(s.escape_default::<0x20>(), Ok(string!("a\\n\\u{7}\\u{e6}\\\"")));
+	assert_eq!(s.escape_debug::<0x20>(),   Ok(string!("a\\n\\u{7}\u{00E6}\\\"")));
+
+	assert_eq!(s.escape_default::<0x4>(), Err(LengthError { remaining: 0x0, count: 0x1 }));
+	assert_eq!(s.escape_debug::<0x4>(),   Err(LengthError { remaining: 0x0, count: 0x1 }));
+}
+
+#[test]
+fn test_string_csv_field() {
+	let s: String<0x10> = string!("a,b");
+	assert_eq!(s.csv_field::<0x10>(), Ok(string!("\"a,b\"")));
+
+	let s: String<0x10> = string!("plain");
+	assert_eq!(s.csv_field::<0x10>(), Ok(string!("plain")));
+
+	let s: String<0x10> = string!("say \"hi\"\n");
+	assert_eq!(s.csv_field::<0x10>(), Ok(string!("\"say \"\"hi\"\"\n\"")));
+
+	let s: String<0x10> = string!("a,b");
+	assert_eq!(s.csv_field::<0x4>(), Err(LengthError { remaining: 0x0, count: 0x1 }));
+}
+
+#[test]
+fn test_string_ascii_titlecase() {
+	let s: String<0x10> = string!("hello world");
+	assert_eq!(s.to_ascii_titlecase(), "Hello World");
+
+	let s: String<0x20> = string!("  mIxEd   \u{00E6}SKE \u{03B1}bc dEF");
+	assert_eq!(s.to_ascii_titlecase(), "  Mixed   \u{00E6}ske \u{03B1}bc Def");
+
+	let mut s: String<0x10> = string!("FOO\tbar");
+	s.make_ascii_titlecase();
+	assert_eq!(s, "Foo\tBar");
+
+	const {
+		let s: String<0x8> = string!("ab cd");
+		assert!(matches!(s.to_ascii_titlecase().as_bytes(), b"Ab Cd"));
+	}
+}
+
+#[test]
+fn test_string_char_boundary() {
+	let s: String<0x8> = string!("a\u{1F54B}b");
+
+	assert_eq!(s.floor_char_boundary(0x0), 0x0);
+	assert_eq!(s.ceil_char_boundary(0x0),  0x0);
+
+	for i in 0x2..=0x4 {
+		assert_eq!(s.floor_char_boundary(i), 0x1);
+		assert_eq!(s.ceil_char_boundary(i),  0x5);
+	}
+
+	assert_eq!(s.floor_char_boundary(0x5), 0x5);
+	assert_eq!(s.ceil_char_boundary(0x5),  0x5);
+
+	assert_eq!(s.floor_char_boundary(0x6), 0x6);
+	assert_eq!(s.ceil_char_boundary(0x6),  0x6);
+	assert_eq!(s.floor_char_boundary(0x8), 0x6);
+	assert_eq!(s.ceil_char_boundary(0x8),  0x6);
+
+	const {
+		let s: String<0x8> = string!("\u{00E6}\u{00F8}");
+
+		assert!(s.floor_char_boundary(0x1) == 0x0);
+		assert!(s.ceil_char_boundary(0x3)  == 0x4);
+	}
+}
+
+#[test]
+fn test_string_match_string() {
+	#[derive(Debug, Eq, PartialEq)]
+	enum Command {
+		Start,
+		Stop,
+		Unknown,
+	}
+
+	const fn parse_command<const N: usize>(s: &String<N>) -> Command {
+		match_string!(s, {
+			"start" | "go" => Command::Start,
+			"stop"         => Command::Stop,
+			_              => Command::Unknown,
+		})
+	}
+
+	let s: String<0x8> = string!("start");
+	assert_eq!(parse_command(&s), Command::Start);
+
+	let s: String<0x8> = string!("go");
+	assert_eq!(parse_command(&s), Command::Start);
+
+	let s: String<0x8> = string!("stop");
+	assert_eq!(parse_command(&s), Command::Stop);
+
+	let s: String<0x8> = string!("stopped");
+	assert_eq!(parse_command(&s), Command::Unknown);
+
+	let s: String<0x8> = string!("");
+	assert_eq!(parse_command(&s), Command::Unknown);
+
+	const {
+		let s: String<0x8> = string!("stop");
+		assert!(matches!(parse_command(&s), Command::Stop));
+	}
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn test_string_prefix_within_width() {
+	let s: String<0x10> = string!("\u{4E2D}\u{6587}");
+
+	assert_eq!(s.prefix_within_width(0x0), "");
+	assert_eq!(s.prefix_within_width(0x1), "");
+	assert_eq!(s.prefix_within_width(0x2), "\u{4E2D}");
+	assert_eq!(s.prefix_within_width(0x3), "\u{4E2D}");
+	assert_eq!(s.prefix_within_width(0x4), "\u{4E2D}\u{6587}");
+
+	let s: String<0x10> = string!("ab\u{4E2D}cd");
+
+	assert_eq!(s.prefix_within_width(0x3),  "ab");
+	assert_eq!(s.prefix_within_width(0x5),  "ab\u{4E2D}c");
+	assert_eq!(s.prefix_within_width(0x10), "ab\u{4E2D}cd");
+}
+
+#[test]
+fn test_string_bytes_mut_guard() {
+	let mut s: String<0x10> = string!("hello world");
+
+	{
+		let mut buf = s.bytes_mut_guard();
+		buf.reverse();
+	}
+
+	assert_eq!(s, "dlrow olleh");
+
+	let mut s: String<0x10> = string!("ab\u{00E6}cd");
+
+	{
+		let mut buf = s.bytes_mut_guard();
+		buf[0x3] = b'x';
+	}
+
+	assert_eq!(s, "ab");
+
+	let mut s: String<0x10> = string!("abc");
+	core::mem::forget(s.bytes_mut_guard());
+
+	assert!(s.is_empty());
+}
+
+#[test]
+fn test_string_join_array() {
+	let parts: [String<0x4>; 0x3] = [string!("usr"), string!("lib"), string!("rust")];
+
+	assert_eq!(String::join_array::<0x3, 0x10>(&parts, "/"), Ok(string!("usr/lib/rust")));
+	assert_eq!(String::concat_array::<0x3, 0x10>(&parts),    Ok(string!("usrlibrust")));
+
+	assert_eq!(String::join_array::<0x3, 0xB>(&parts, "/"), Err(LengthError { remaining: 0x3, count: 0x4 }));
+	assert_eq!(String::concat_array::<0x3, 0x9>(&parts),    Err(LengthError { remaining: 0x3, count: 0x4 }));
+
+	let parts: [String<0x4>; 0x0] = [];
+	assert_eq!(String::join_array::<0x0, 0x0>(&parts, "/"), Ok(string!()));
+
+	const {
+		let parts: [String<0x4>; 0x2] = [string!("a"), string!("b")];
+
+		let Ok(s) = String::join_array::<0x2, 0x4>(&parts, ", ") else { panic!() };
+		assert!(matches!(s.as_bytes(), b"a, b"));
+	}
+}
+
+#[test]
+fn test_string_from_char() {
+	const SKULL: String<0x4> = match String::from_char('\u{1F480}') {
+		Ok(s)   => s,
+		Err(..) => panic!(),
+	};
+
+	assert_eq!(SKULL, "\u{1F480}");
+
+	assert_eq!(String::<0x1>::from_char('a'), Ok(string!("a")));
+	assert_eq!(String::<0x1>::from_char('\u{1F480}'), Err(LengthError { remaining: 0x1, count: 0x4 }));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_string_zeroize() {
+	use zeroize::Zeroize;
+
+	let mut s: String<0x8> = string!("password");
+
+	s.truncate(0x4);
+	s.zeroize();
+
+	assert!(s.is_empty());
+	assert_eq!(s.into_raw_parts(), ([0x00; 0x8], 0x0));
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_string_bincode() {
+	use bincode::config;
+	use bincode::error::DecodeError;
+
+	let config = config::standard();
+
+	let s: String<0x10> = string!("hello world");
+
+	let mut buf = [0x00; 0x20];
+	let count = bincode::encode_into_slice(s, &mut buf, config).unwrap();
+
+	assert_eq!(&buf[..count], b"\x0Bhello world");
+
+	let (t, _) = bincode::decode_from_slice::<String<0x10>, _>(&buf[..count], config).unwrap();
+	assert_eq!(t, s);
+
+	assert!(matches!(
+		bincode::decode_from_slice::<String<0x4>, _>(&buf[..count], config),
+		Err(DecodeError::Other(..)),
+	));
+
+	assert!(matches!(
+		bincode::decode_from_slice::<String<0x4>, _>(b"\x02a\xFF", config),
+		Err(DecodeError::Utf8 { .. }),
+	));
+}
+
+#[test]
+fn test_string_chars() {
+	let s: String<0x10> = string!("a\u{00E6}\u{1F480}b");
+
+	assert!(s.chars().eq(['a', '\u{00E6}', '\u{1F480}', 'b']));
+	assert!(s.char_indices().eq([(0x0, 'a'), (0x1, '\u{00E6}'), (0x3, '\u{1F480}'), (0x7, 'b')]));
+
+	let mut chars = s.into_chars();
+
+	assert_eq!(chars.next(), Some('a'));
+	assert_eq!(chars.as_str(), "\u{00E6}\u{1F480}b");
+	assert!(chars.eq(['\u{00E6}', '\u{1F480}', 'b']));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_into_chars() {
+	use alloc::vec::Vec;
+
+	let s: String<0x10> = string!("\u{00E6}\u{00F8}\u{00E5}!");
+
+	let chars: Vec<char> = s.into_chars().collect();
+	assert_eq!(chars, ['\u{00E6}', '\u{00F8}', '\u{00E5}', '!']);
+}
+
+#[test]
+fn test_string_insert_char_at() {
+	let mut s: String<0x10> = string!("\u{1F480}b");
+
+	s.insert_char_at(0x1, 'a').unwrap();
+	assert_eq!(s, "\u{1F480}ab");
+
+	s.insert_str_at(0x3, "\u{00E6}\u{00F8}").unwrap();
+	assert_eq!(s, "\u{1F480}ab\u{00E6}\u{00F8}");
+
+	s.insert_str_at(0x0, "x").unwrap();
+	assert_eq!(s, "x\u{1F480}ab\u{00E6}\u{00F8}");
+
+	assert_eq!(s.insert_str_at(0x2, "\u{1F480}\u{1F480}"), Err(LengthError { remaining: 0x5, count: 0x8 }));
+}
+
+#[test]
+#[should_panic]
+fn test_string_insert_char_at_past_end() {
+	let mut s: String<0x10> = string!("\u{1F480}");
+
+	let _ = s.insert_char_at(0x2, 'a');
+}
+
+#[test]
+fn test_string_eq_bytes() {
+	let s: String<0x8> = string!("abc");
+
+	assert_eq!(s, b"abc");
+	assert_eq!(s, *b"abc");
+	assert_eq!(s, b"abc".as_slice());
+	assert_eq!(s, *b"abc".as_slice());
+
+	assert_eq!(b"abc", s);
+	assert_eq!(*b"abc", s);
+	assert_eq!(b"abc".as_slice(), s);
+	assert_eq!(*b"abc".as_slice(), s);
+
+	assert_ne!(s, b"abd");
+	assert_ne!(s, b"ab");
+	assert_ne!(s, b"abcd");
+	assert_ne!(b"abcd", s);
+}
+
+#[test]
+fn test_string_try_push() {
+	let s = String::<0x8>::new()
+		.try_push('a')
+		.and_then(|s| s.try_push_str("bc"))
+		.and_then(|s| s.try_push('\u{00E6}'));
+
+	assert_eq!(s, Ok(string!("abc\u{00E6}")));
+
+	let s = String::<0x4>::new()
+		.try_push_str("abc")
+		.and_then(|s| s.try_push_str("de"))
+		.and_then(|s| s.try_push('f'));
+
+	assert_eq!(s, Err((string!("abc"), LengthError { remaining: 0x1, count: 0x2 })));
+
+	const {
+		let Ok(s) = String::<0x4>::new().try_push('a') else { panic!() };
+		assert!(matches!(s.as_bytes(), b"a"));
+	}
+}
+
+#[test]
+fn test_string_replace_range() {
+	let mut s: String<0x10> = string!("hello world");
+
+	s.replace_range(0x0..0x5, "HOWDY").unwrap();
+	assert_eq!(s, "HOWDY world");
+
+	s.replace_range(..0x5, "greetings,").unwrap();
+	assert_eq!(s, "greetings, world");
+
+	s.replace_range(0x9.., "!").unwrap();
+	assert_eq!(s, "greetings!");
+
+	s.replace_range(0x1..=0x7, "\u{00E6}").unwrap();
+	assert_eq!(s, "g\u{00E6}s!");
+
+	assert_eq!(
+		s.replace_range(0x1..0x3, "abcdefghijklmn"),
+		Err(LengthError { remaining: 0xD, count: 0xE }),
+	);
+
+	assert_eq!(s, "g\u{00E6}s!");
+
+	s.replace_range(0x1..0x3, "abcdefghijklm").unwrap();
+	assert_eq!(s, "gabcdefghijklms!");
+}
+
+#[test]
+#[should_panic]
+fn test_string_replace_range_non_boundary() {
+	let mut s: String<0x10> = string!("a\u{00E6}b");
+
+	let _ = s.replace_range(0x0..0x2, "c");
+}
+
+#[test]
+fn test_string_equals() {
+	const {
+		let s: String<0x8> = string!("l\u{00E6}s");
+
+		assert!(s.equals("l\u{00E6}s"));
+		assert!(!s.equals("l\u{00F8}s"));
+		assert!(!s.equals("l\u{00E6}"));
+		assert!(!s.equals("l\u{00E6}se"));
+
+		let s: String<0x0> = string!();
+		assert!(s.equals(""));
+	}
+
+	let s: String<0x8> = string!("abc");
+	assert!(s.equals("abc"));
+}
+
+#[test]
+fn test_string_trim() {
+	const {
+		let s: String<0x10> = string!(" \t foo bar\t \n");
+
+		assert!(matches!(s.trim().as_bytes(),       b"foo bar"));
+		assert!(matches!(s.trim_start().as_bytes(), b"foo bar\t \n"));
+		assert!(matches!(s.trim_end().as_bytes(),   b" \t foo bar"));
+
+		let s: String<0x10> = string!("\u{3000}\u{00E6}\u{00A0}");
+
+		assert!(matches!(s.trim().as_bytes(), b"\xC3\xA6"));
+
+		let s: String<0x10> = string!("foo");
+
+		assert!(matches!(s.trim().as_bytes(),       b"foo"));
+		assert!(matches!(s.trim_start().as_bytes(), b"foo"));
+		assert!(matches!(s.trim_end().as_bytes(),   b"foo"));
+
+		let s: String<0x10> = string!(" \t ");
+
+		assert!(s.trim().is_empty());
+		assert!(s.trim_start().is_empty());
+		assert!(s.trim_end().is_empty());
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_display_pad() {
+	use alloc::format;
+
+	let s: String<0x8> = string!("hi");
+
+	assert_eq!(format!("{s:>8}"),  "      hi");
+	assert_eq!(format!("{s:<4}|"), "hi  |");
+	assert_eq!(format!("{s:*^6}"), "**hi**");
+
+	let s: String<0x8> = string!("\u{00E6}\u{00F8}\u{00E5}");
+
+	assert_eq!(format!("{s:.2}"),   format!("{:.2}", s.as_str()));
+	assert_eq!(format!("{s:>4.1}"), "   \u{00E6}");
+}
+
+#[test]
+fn test_string_try_from_chars() {
+	let chars = ['a', '\u{00E9}', '\u{1D11E}'];
+
+	assert_eq!(String::<0x8>::try_from(chars), Ok(string!("a\u{00E9}\u{1D11E}")));
+	assert_eq!(String::<0x7>::try_from(chars), Ok(string!("a\u{00E9}\u{1D11E}")));
+	assert_eq!(String::<0x6>::try_from(chars), Err(LengthError { remaining: 0x3, count: 0x4 }));
+
+	assert_eq!(String::<0x8>::try_from(&chars[..0x2]), Ok(string!("a\u{00E9}")));
+	assert_eq!(String::<0x2>::try_from(&chars[..0x2]), Err(LengthError { remaining: 0x1, count: 0x2 }));
+}
+
+#[test]
+fn test_string_char_to_byte() {
+	let s: String<0x10> = string!("a\u{00E6}\u{1F480}b");
+
+	assert_eq!(s.nth_char(0x0), Some('a'));
+	assert_eq!(s.nth_char(0x1), Some('\u{00E6}'));
+	assert_eq!(s.nth_char(0x2), Some('\u{1F480}'));
+	assert_eq!(s.nth_char(0x3), Some('b'));
+	assert_eq!(s.nth_char(0x4), None);
+	assert_eq!(s.nth_char(0x9), None);
+
+	for (char_index, byte_index) in [(0x0, 0x0), (0x1, 0x1), (0x2, 0x3), (0x3, 0x7), (0x4, 0x8)] {
+		assert_eq!(s.char_to_byte(char_index), Some(byte_index));
+		assert_eq!(s.byte_to_char(byte_index), Some(char_index));
+	}
+
+	assert_eq!(s.char_to_byte(0x5), None);
+
+	assert_eq!(s.byte_to_char(0x2), None);
+	assert_eq!(s.byte_to_char(0x5), None);
+	assert_eq!(s.byte_to_char(0x9), None);
+
+	const {
+		let s: String<0x8> = string!("\u{00F8}l");
+
+		assert!(matches!(s.nth_char(0x1),     Some('l')));
+		assert!(matches!(s.char_to_byte(0x1), Some(0x2)));
+		assert!(matches!(s.byte_to_char(0x3), Some(0x2)));
+	}
+}
+
+#[test]
+fn test_string_checked_push() {
+	let mut s: String<0x4> = string!();
+
+	let mut overflow = None;
+
+	for c in "ab\u{00E6}c".chars() {
+		if let Err(c) = s.checked_push(c) {
+			overflow = Some(c);
+			break;
+		}
+	}
+
+	assert_eq!(s, "ab\u{00E6}");
+	assert_eq!(overflow, Some('c'));
+
+	let mut s: String<0x4> = string!("abc");
+
+	assert_eq!(s.checked_push('\u{1F480}'), Err('\u{1F480}'));
+	assert_eq!(s, "abc");
+
+	assert_eq!(s.checked_push('d'), Ok(()));
+	assert_eq!(s, "abcd");
+}
+
+#[test]
+fn test_string_substr() {
+	const {
+		let s: String<0x10> = string!("l\u{00E6}s mere");
+
+		assert!(matches!(s.substr(0x0, 0x3), Some(s) if s.len() == 0x3));
+		assert!(matches!(s.substr(0x5, 0x9), Some(s) if s.len() == 0x4));
+		assert!(matches!(s.substr(0x9, 0x9), Some(s) if s.is_empty()));
+
+		assert!(s.substr(0x2, 0x4).is_none());
+		assert!(s.substr(0x0, 0x2).is_none());
+		assert!(s.substr(0x5, 0xA).is_none());
+		assert!(s.substr(0x5, 0x3).is_none());
+	}
+
+	let s: String<0x10> = string!("l\u{00E6}s mere");
+
+	assert_eq!(s.substr(0x0, 0x3), Some("l\u{00E6}"));
+	assert_eq!(s.substr(0x5, 0x9), Some("mere"));
+}
+
+#[test]
+fn test_string_invariants() {
+	// NOTE: All of the following mutations also assert
+	// the string's invariants internally when debug as-
+	// sertions are enabled.
+
+	fn is_valid<const N: usize>(s: &String<N>) -> bool {
+		str::from_utf8(s.as_bytes()).is_ok() && s.len() <= N
+	}
+
+	let mut s: String<0x10> = string!("\u{00E6}\u{1F480}\u{00F8}");
+
+	s.insert_str(0x2, "\u{5149}").unwrap();
+	assert!(is_valid(&s));
+
+	assert_eq!(s.remove(0x5), '\u{1F480}');
+	assert!(is_valid(&s));
+
+	s.replace_range(0x2..0x5, "\u{1F480}").unwrap();
+	assert!(is_valid(&s));
+
+	let t = s.split_off(0x2);
+	assert!(is_valid(&s));
+	assert!(is_valid(&t));
+
+	assert!(s.consume_prefix("\u{00E6}"));
+	assert!(is_valid(&s));
+
+	let mut s: String<0x10> = string!("\u{00E6} \u{00F8}  \u{00E5}");
+
+	s.normalize_spaces();
+	assert!(is_valid(&s));
+
+	s.truncate_chars(0x2);
+	assert!(is_valid(&s));
+
+	s.truncate(0x2);
+	assert!(is_valid(&s));
+
+	{
+		let mut buf = s.bytes_mut_guard();
+		buf[0x1] = 0xFF;
+	}
+
+	assert!(is_valid(&s));
+}
+
+#[test]
+fn test_string_oct_fixed_string() {
+	use conststr::FixedString;
+
+	assert_eq!(FixedString::<0x8>::MAX_ENCODED_SIZE, 0x8);
+
+	for s in [string!(""), string!("abc"), string!("\u{00E6}\u{1F480}"), string!("conststr")] {
+		let s: FixedString<0x8> = FixedString::new(s);
+
+		let mut buf = [0xFF; 0x8];
+
+		let mut output = Output::new(&mut buf);
+		s.encode(&mut output).unwrap();
+
+		assert_eq!(buf[..s.len()], *s.as_bytes());
+		assert!(buf[s.len()..].iter().all(|&octet| octet == 0x00));
+
+		let mut input = Input::new(&buf);
+		assert_eq!(FixedString::<0x8>::decode(&mut input), Ok(s));
+	}
+
+	let data = *b"a\xFF\x00\x00";
+	let mut input = Input::new(&data);
+
+	assert_eq!(FixedString::<0x4>::decode(&mut input), Err(Utf8Error { value: 0xFF, index: 0x1 }));
+}
+
+#[test]
+fn test_string_oct_short_string() {
+	use conststr::ShortString;
+
+	assert_eq!(ShortString::<0x8>::MAX_ENCODED_SIZE, 0x9);
+
+	for s in [string!(""), string!("abc"), string!("\u{00E6}\u{1F480}"), string!("conststr")] {
+		let s: ShortString<0x8> = ShortString::new(s);
+
+		let mut buf = [0xFF; 0x9];
+
+		let mut output = Output::new(&mut buf);
+		s.encode(&mut output).unwrap();
+
+		assert_eq!(usize::from(buf[0x0]), s.len());
+		assert_eq!(buf[0x1..=s.len()], *s.as_bytes());
+		assert!(buf[0x1 + s.len()..].iter().all(|&octet| octet == 0xFF));
+
+		let mut input = Input::new(&buf);
+		assert_eq!(ShortString::<0x8>::decode(&mut input), Ok(s));
+	}
+
+	let data = *b"\x09conststr!";
+	let mut input = Input::new(&data);
+
+	let _ = ShortString::<0x8>::decode(&mut input).unwrap_err();
+
+	// The greatest, allowed capacity is `255`. Greater
+	// capacities are rejected at compile-time.
+
+	assert_eq!(ShortString::<0xFF>::MAX_ENCODED_SIZE, 0x100);
+
+	let s: ShortString<0xFF> = ShortString::default();
+
+	let mut buf = [0xFF; 0x1];
+
+	let mut output = Output::new(&mut buf);
+	s.encode(&mut output).unwrap();
+
+	assert_eq!(buf, [0x00]);
+}
+
+#[test]
+fn test_string_ascii_case_insensitive() {
+	use conststr::AsciiCaseInsensitive;
+
+	let a: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("A"));
+	let b: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("a"));
+	let c: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("B"));
+
+	assert_eq!(a, b);
+	assert_eq!(a.cmp(&b), Ordering::Equal);
+	assert_eq!(b.cmp(&c), Ordering::Less);
+	assert_eq!(c.cmp(&a), Ordering::Greater);
+
+	let a: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("\u{00C6}"));
+	let b: AsciiCaseInsensitive<String<0x8>> = AsciiCaseInsensitive::new(string!("\u{00E6}"));
+
+	assert_ne!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_string_ascii_case_insensitive_hash() {
+	use conststr::AsciiCaseInsensitive;
+	use std::collections::HashMap;
+
+	let mut map = HashMap::new();
+
+	let key: String<0x8> = string!("Key");
+	map.insert(AsciiCaseInsensitive::new(key), 0x1);
+
+	let key: String<0x8> = string!("key");
+	assert_eq!(map.get(&AsciiCaseInsensitive::new(key)), Some(&0x1));
+
+	let key: String<0x8> = string!("KEY");
+	map.insert(AsciiCaseInsensitive::new(key), 0x2);
+
+	assert_eq!(map.len(), 0x1);
+
+	let key: String<0x8> = string!("kEy");
+	assert_eq!(map.get(&AsciiCaseInsensitive::new(key)), Some(&0x2));
+}
+
+#[test]
+fn test_string_lines_iter() {
+	for s in ["foo\nbar\r\nbaz", "foo\nbar\r\nbaz\n", "\n\r\n", "a\rb\r", "", "\r", "\r\n\r\nx\r\n"] {
+		let t: String<0x20> = String::from_str(s).unwrap();
+		assert!(t.lines_iter().eq(s.lines()), "{s:?}");
+	}
+
+	const {
+		let s: String<0x10> = string!("a\r\nbc\n");
+		let mut lines = s.lines_iter();
+
+		assert!(matches!(lines.next_line(), Some(line) if line.len() == 0x1));
+		assert!(matches!(lines.next_line(), Some(line) if line.len() == 0x2));
+		assert!(lines.next_line().is_none());
+
+		let s: String<0x10> = string!();
+		assert!(s.lines_iter().next_line().is_none());
+	}
+}
+
+#[test]
+fn test_string_split_str() {
+	let s: String<0x10> = string!("a,,b,");
+	assert!(s.split_str(",").eq(["a", "", "b", ""]));
+
+	let s: String<0x10> = string!("a::b::::c");
+	assert!(s.split_str("::").eq(["a", "b", "", "c"]));
+
+	for (s, delim) in [("", ","), ("", ""), ("a\u{00E6}b", ""), (",", ","), ("aaa", "aa"), ("abc", "abcd"), ("\u{00E6}\u{00F8}\u{00E6}", "\u{00F8}")] {
+		let t: String<0x10> = String::from_str(s).unwrap();
+		assert!(t.split_str(delim).eq(s.split(delim)), "{s:?} by {delim:?}");
+	}
+}
+
+#[test]
+fn test_string_hash_capacity() {
+	use core::hash::{Hash, Hasher};
+
+	/// FNV-1a, for deterministic hashing.
+	struct Fnv(u64);
+
+	impl Hasher for Fnv {
+		fn finish(&self) -> u64 {
+			self.0
+		}
+
+		fn write(&mut self, bytes: &[u8]) {
+			for &octet in bytes {
+				self.0 ^= u64::from(octet);
+				self.0  = self.0.wrapping_mul(0x0000_0100_0000_01B3);
+			}
+		}
+	}
+
+	fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+		let mut hasher = Fnv(0xCBF2_9CE4_8422_2325);
+		value.hash(&mut hasher);
+
+		hasher.finish()
+	}
+
+	let a: String<0x8>  = string!("hello");
+	let b: String<0x10> = string!("hello");
+
+	assert_eq!(a, b);
+	assert_eq!(hash(&a), hash(&b));
+	assert_eq!(hash(&a), hash("hello"));
+
+	// Garbage past the length must not be hashed.
+	let mut c: String<0x10> = string!("hello world");
+	c.truncate(0x5);
+
+	assert_eq!(hash(&c), hash(&b));
+
+	let d: String<0x10> = string!("hellp");
+	assert_ne!(hash(&d), hash(&b));
+}
+
+#[test]
+fn test_string_from_str_truncating() {
+	assert_eq!(String::<0x8>::from_str_truncating("abc"), "abc");
+	assert_eq!(String::<0x3>::from_str_truncating("abcdef"), "abc");
+
+	let s = String::<0x4>::from_str_truncating("ab\u{1F480}");
+	assert_eq!(s, "ab");
+	let _ = str::from_utf8(s.as_bytes()).unwrap();
+
+	let s = String::<0x5>::from_str_truncating("\u{00E6}\u{00F8}\u{00E5}");
+	assert_eq!(s, "\u{00E6}\u{00F8}");
+
+	assert_eq!(String::<0x0>::from_str_truncating("a"), "");
+
+	const {
+		let s = String::<0x3>::from_str_truncating("a\u{5149}");
+		assert!(matches!(s.as_bytes(), b"a"));
+	}
+}
+
+#[test]
+fn test_string_char_slice() {
+	let s: String<0x10> = string!("a\u{00E6}\u{1F480}b\u{00F8}");
+
+	assert_eq!(s.char_slice(0x0..0x5), Some("a\u{00E6}\u{1F480}b\u{00F8}"));
+	assert_eq!(s.char_slice(0x1..0x3), Some("\u{00E6}\u{1F480}"));
+	assert_eq!(s.char_slice(0x2..0x4), Some("\u{1F480}b"));
+	assert_eq!(s.char_slice(0x5..0x5), Some(""));
+
+	assert_eq!(s.char_slice(0x3..0x6), None);
+	assert_eq!(s.char_slice(0x6..0x6), None);
+
+	#[allow(clippy::reversed_empty_ranges)]
+	let range = 0x3..0x2;
+	assert_eq!(s.char_slice(range), None);
+}
+
+#[test]
+fn test_string_reverse() {
+	let s: String<0x10> = string!("abc");
+	assert_eq!(s.reverse(), "cba");
+
+	let s: String<0x10> = string!("a\u{00E6}\u{1F480}b\u{2020}");
+	let r = s.reverse();
+
+	assert_eq!(r, "\u{2020}b\u{1F480}\u{00E6}a");
+	assert_eq!(r.len(), s.len());
+	assert_eq!(str::from_utf8(r.as_bytes()), Ok(r.as_str()));
+	assert_eq!(r.reverse(), s);
+
+	const REVERSED: String<0x4> = string!("\u{00F8}l").reverse();
+	assert_eq!(REVERSED, "l\u{00F8}");
+}
+
+#[test]
+fn test_string_count() {
+	const TEMPLATE: String<0x20> = string!("{a} + {b} = {{c}}");
+
+	const {
+		assert!(TEMPLATE.count_char('{') == 0x4);
+		assert!(TEMPLATE.count_char('{') == TEMPLATE.count_char('}'));
+		assert!(TEMPLATE.count_str("{{") == 0x1);
+	};
+
+	let s: String<0x10> = string!("aaaaa");
+
+	assert_eq!(s.count_str("aa"),  0x2);
+	assert_eq!(s.count_str("aaa"), 0x1);
+	assert_eq!(s.count_str("b"),   0x0);
+
+	let s: String<0x10> = string!("\u{00E6}\u{1F480}\u{00E6}");
+
+	assert_eq!(s.count_char('\u{00E6}'), 0x2);
+	assert_eq!(s.count_char('\u{1F480}'), 0x1);
+	assert_eq!(s.count_str(""), s.matches("").count());
+	assert_eq!(String::<0x0>::new().count_str(""), 0x1);
+}
+
+#[test]
+fn test_string_try_apply() {
+	let mut s: String<0x10> = string!("Hello, World!");
+
+	let len = s.len();
+	s.try_apply(|buf| {
+		for octet in &mut buf[..len] {
+			*octet = match *octet {
+				b'A'..=b'Z' => (*octet - b'A' + 0xD) % 0x1A + b'A',
+				b'a'..=b'z' => (*octet - b'a' + 0xD) % 0x1A + b'a',
+
+				_ => *octet,
+			};
+		}
+
+		len
+	}).unwrap();
+
+	assert_eq!(s, "Uryyb, Jbeyq!");
+
+	s.try_apply(|buf| {
+		buf[0xD..0x10].copy_from_slice(b"???");
+		0x10
+	}).unwrap();
+
+	assert_eq!(s, "Uryyb, Jbeyq!???");
+
+	let before = s;
+
+	let result = s.try_apply(|buf| {
+		buf[0x0] = 0xFF;
+		0x4
+	});
+
+	assert_eq!(result, Err(Utf8Error { value: 0xFF, index: 0x0 }));
+	assert_eq!(s, before);
+	assert_eq!(s.len(), 0x10);
+}
+
+#[test]
+fn test_string_array_conversion() {
+	let mut s: String<0x8> = string!("conststr");
+	s.truncate(0x5);
+
+	let buf: [u8; 0x8] = s.into();
+	assert_eq!(buf, *b"const\0\0\0");
+
+	// Converting back with `TryFrom` keeps the padding.
+
+	let t = String::<0x8>::try_from(buf).unwrap();
+	assert_eq!(t.len(), 0x8);
+	assert_ne!(t, s);
+
+	// Converting back with `from_nul_padded` round-
+	// trips.
+
+	assert_eq!(String::<0x8>::from_nul_padded(buf), Ok(s));
+
+	let s: String<0x4> = string!("\u{00E6}\u{00F8}");
+	let buf: [u8; 0x4] = s.into();
+
+	assert_eq!(String::try_from(buf), Ok(s));
+	assert_eq!(String::from_nul_padded(buf), Ok(s));
+
+	let s: String<0x4> = string!();
+	let buf: [u8; 0x4] = s.into();
+
+	assert_eq!(String::from_nul_padded(buf), Ok(s));
+
+	const {
+		let Ok(s) = String::<0x8>::from_nul_padded(*b"abc\0\0") else { panic!() };
+		assert!(matches!(s.as_bytes(), b"abc"));
+	}
+
+	assert_eq!(String::<0x8>::from_nul_padded(*b"ab\xFF\0"), Err(Utf8Error { value: 0xFF, index: 0x2 }));
+}
+
+#[test]
+fn test_string_parse() {
+	const NUMBER:  String<0x8> = string!("12345");
+	const INVALID: String<0x8> = string!("12a");
+	const EMPTY:   String<0x8> = String::new();
+
+	const {
+		assert!(matches!(NUMBER.parse_u64(), Ok(12345)));
+		assert!(matches!(INVALID.parse_u64(), Err(..)));
+		assert!(matches!(EMPTY.parse_u64(), Err(..)));
+	};
+
+	let s: String<0x18> = string!("18446744073709551615");
+	assert_eq!(s.parse_u64(), Ok(u64::MAX));
+
+	let s: String<0x18> = string!("18446744073709551616");
+	let _ = s.parse_u64().unwrap_err();
+
+	let s: String<0x8> = string!("-12");
+	assert_eq!(s.parse_into::<i8>(), Ok(-0xC));
+
+	let s: String<0x8> = string!("256");
+	let _ = s.parse_into::<u8>().unwrap_err();
+}
+
+#[test]
+fn test_string_pad() {
+	let mut s: String<0x8> = string!("hi");
+
+	s.pad_end(0x6, ' ').unwrap();
+	assert_eq!(s, "hi    ");
+
+	let mut s: String<0x8> = string!("hi");
+
+	s.pad_start(0x6, ' ').unwrap();
+	assert_eq!(s, "    hi");
+
+	let mut s: String<0x8> = string!("hi");
+
+	s.pad_end(0x7, '\u{00B7}').unwrap();
+	assert_eq!(s, "hi\u{00B7}\u{00B7}");
+
+	s.pad_start(0x7, '\u{00B7}').unwrap();
+	assert_eq!(s, "hi\u{00B7}\u{00B7}");
+
+	s.pad_start(0x8, '-').unwrap();
+	assert_eq!(s, "--hi\u{00B7}\u{00B7}");
+
+	assert_eq!(s.pad_end(0x9, ' '), Err(LengthError { remaining: 0x0, count: 0x1 }));
+	assert_eq!(s, "--hi\u{00B7}\u{00B7}");
+
+	s.pad_end(0x2, ' ').unwrap();
+	assert_eq!(s, "--hi\u{00B7}\u{00B7}");
+}
+
+#[test]
+fn test_string_is_blank() {
+	const EMPTY:  String<0x8> = String::new();
+	const SPACES: String<0x8> = string!("  \t\r\n ");
+	const MIXED:  String<0x8> = string!(" a ");
+	const NBSP:   String<0x8> = string!(" \u{00A0}\u{3000}");
+
+	const {
+		assert!(EMPTY.is_blank());
+		assert!(SPACES.is_blank());
+		assert!(!MIXED.is_blank());
+		assert!(NBSP.is_blank());
+	};
+}
+
+#[test]
+fn test_string_copy_from() {
+	let mut s: String<0x10> = string!("conststr");
+
+	let src: String<0x6> = string!("\u{00E6}\u{00F8}\u{00E5}");
+	s.copy_from(&src).unwrap();
+	assert_eq!(s, "\u{00E6}\u{00F8}\u{00E5}");
+
+	let src: String<0x10> = string!("0123456789ABCDEF");
+	s.copy_from(&src).unwrap();
+	assert_eq!(s, "0123456789ABCDEF");
+
+	let mut s: String<0x4> = string!("abc");
+
+	let src: String<0x8> = string!("abcde");
+	assert_eq!(s.copy_from(&src), Err(LengthError { remaining: 0x4, count: 0x5 }));
+	assert_eq!(s, "abc");
+}
+
+#[test]
+fn test_string_split_at_owned() {
+	let s: String<0x10> = string!("a\u{00E6}\u{1F480}b");
+
+	let (head, tail) = s.split_at_owned(0x3);
+
+	assert_eq!(head, "a\u{00E6}");
+	assert_eq!(tail, "\u{1F480}b");
+
+	let mut t = head;
+	t.push_str(&tail).unwrap();
+	assert_eq!(t, s);
+
+	assert_eq!(s.split_at_owned_checked(0x0), Some((string!(""), s)));
+	assert_eq!(s.split_at_owned_checked(s.len()), Some((s, string!(""))));
+	assert_eq!(s.split_at_owned_checked(0x2), None);
+	assert_eq!(s.split_at_owned_checked(0x9), None);
+}
+
+#[test]
+fn test_string_repeat_char() {
+	const {
+		assert!(matches!(String::<0x5>::repeat_char('-', 0x5), Ok(s) if s.equals("-----")));
+		assert!(matches!(String::<0x5>::repeat_char('-', 0x6), Err(LengthError { remaining: 0x5, count: 0x6 })));
+	};
+
+	assert_eq!(String::<0x8>::repeat_char('\u{2500}', 0x2), Ok(string!("\u{2500}\u{2500}")));
+	assert_eq!(String::<0x8>::repeat_char('\u{2500}', 0x3), Err(LengthError { remaining: 0x8, count: 0x9 }));
+	assert_eq!(String::<0x8>::repeat_char('a', 0x0), Ok(string!("")));
+
+	assert_eq!(String::<0x8>::repeat_char('\u{1F480}', usize::MAX), Err(LengthError { remaining: 0x8, count: usize::MAX }));
+}
+
+#[test]
+fn test_string_to_case() {
+	let s: String<0x8> = string!("stra\u{00DF}e");
+
+	assert_eq!(s.to_uppercase::<0x8>(), Ok(string!("STRASSE")));
+	assert_eq!(s.to_uppercase::<0x6>(), Err(LengthError { remaining: 0x0, count: 0x1 }));
+
+	let s: String<0x10> = string!("\u{00C6}BLE \u{0130}");
+
+	assert_eq!(s.to_lowercase::<0x10>(), Ok(string!("\u{00E6}ble i\u{0307}")));
+	assert_eq!(s.to_lowercase::<0x10>().unwrap().to_uppercase::<0x10>(), Ok(string!("\u{00C6}BLE I\u{0307}")));
+}
+
+#[test]
+fn test_string_contains_char() {
+	const S: String<0x10> = string!("a-\u{00E6}\u{1F480}");
+
+	const {
+		assert!(S.contains_char('-'));
+		assert!(S.contains_char('\u{00E6}'));
+		assert!(S.contains_char('\u{1F480}'));
+		assert!(!S.contains_char('b'));
+		assert!(!S.contains_char('\u{00C6}'));
+	};
+}
+
+#[test]
+fn test_string_format_into() {
+	let (a, b) = (0x2A, -0x7);
+
+	assert_eq!(format_into!(String<0x20>, "{a}-{b}"), Ok(string!("42--7")));
+	assert_eq!(format_into!(String<0x20>, "{:04}:{:+}", a * 0x2, b), Ok(string!("0084:-7")));
+	assert_eq!(format_into!(String<0x4>, "{a}-{b}"), Err(core::fmt::Error));
+
+	let mut s: String<0x8> = string!("e=");
+
+	assert_eq!(core::fmt::Write::write_fmt(&mut s, format_args!("{:.2}", core::f64::consts::E)), Ok(()));
+	assert_eq!(s, "e=2.72");
+}
+
+#[test]
+fn test_string_trim_capacity() {
+	const NAME:   String<0x40> = string!("hello");
+	const PACKED: String<0x8>  = NAME.trim_capacity();
+
+	assert_eq!(PACKED, "hello");
+
+	let s: String<0x5> = NAME.trim_capacity();
+	assert_eq!(s, "hello");
+}
+
+#[test]
+#[should_panic]
+fn test_string_trim_capacity_overflow() {
+	let s: String<0x40> = string!("hello world");
+	let _: String<0x8> = s.trim_capacity();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_try_from_cow() {
+	use alloc::borrow::Cow;
+	use alloc::borrow::ToOwned;
+
+	let borrowed: Cow<str> = Cow::Borrowed("conststr");
+	let owned:    Cow<str> = Cow::Owned("\u{00E6}\u{00F8}\u{00E5}".to_owned());
+
+	assert_eq!(String::<0x8>::try_from(borrowed.clone()), Ok(string!("conststr")));
+	assert_eq!(String::<0x8>::try_from(owned), Ok(string!("\u{00E6}\u{00F8}\u{00E5}")));
+
+	assert_eq!(String::<0x4>::try_from(borrowed), Err(LengthError { remaining: 0x4, count: 0x8 }));
+}
+
+#[test]
+fn test_string_find_all_into() {
+	const ROW: String<0x20> = string!("id,name,\u{00E6},,x");
+
+	const {
+		let (indices, count) = ROW.find_all_into::<0x8>(',');
+
+		assert!(count == 0x4);
+		assert!(indices[0x0] == 0x2);
+		assert!(indices[0x1] == 0x7);
+		assert!(indices[0x2] == 0xA);
+		assert!(indices[0x3] == 0xB);
+		assert!(indices[0x4] == 0x0);
+
+		let (indices, count) = ROW.find_all_into::<0x4>(';');
+
+		assert!(count == 0x0);
+		assert!(indices[0x0] == 0x0);
+	};
+
+	let expected: ([usize; 0x8], usize) = {
+		let mut indices = [0x0; 0x8];
+		let mut count   = 0x0;
+
+		for (i, (index, _)) in ROW.match_indices(',').enumerate() {
+			indices[i] = index;
+			count += 0x1;
+		}
+
+		(indices, count)
+	};
+
+	assert_eq!(ROW.find_all_into::<0x8>(','), expected);
+
+	assert_eq!(ROW.find_all_into::<0x2>(','), ([0x2, 0x7], 0x2));
+	assert_eq!(ROW.find_all_into::<0x2>('\u{00E6}'), ([0x8, 0x0], 0x1));
+	assert_eq!(ROW.find_all_into::<0x2>(';'), ([0x0, 0x0], 0x0));
+}
+
+#[test]
+fn test_string_extend_repeat() {
+	let mut s: String<0x8> = string!("ab");
+
+	s.extend_repeat('.', 0x3).unwrap();
+	assert_eq!(s, "ab...");
+
+	s.extend_repeat('\u{00B7}', 0x0).unwrap();
+	assert_eq!(s, "ab...");
+
+	assert_eq!(s.extend_repeat('\u{00B7}', 0x2), Err(LengthError { remaining: 0x3, count: 0x4 }));
+	assert_eq!(s, "ab...");
+
+	s.extend_repeat('-', 0x3).unwrap();
+	assert_eq!(s, "ab...---");
+
+	assert_eq!(s.extend_repeat('-', usize::MAX), Err(LengthError { remaining: 0x0, count: usize::MAX }));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_error() {
+	use alloc::string::ToString;
+	use core::error::Error;
+
+	let length = || LengthError { remaining: 0x4, count: 0x5 };
+	let utf8   = || Utf8Error { value: 0xFF, index: 0x1 };
+
+	assert_eq!(StringError::from(length()), StringError::Length(length()));
+	assert_eq!(StringError::from(utf8()),   StringError::Utf8(utf8()));
+
+	assert_eq!(
+		StringError::from(length()).to_string(),
+		"unable to contain string: collection with (4) remaining size cannot hold (5) more elements",
+	);
+
+	assert_eq!(
+		StringError::from(utf8()).to_string(),
+		"unable to decode string: found invalid utf-8 octet 0xFF at offset (1)",
+	);
+
+	assert!(StringError::from(length()).source().is_some());
+	assert!(StringError::from(utf8()).source().is_some());
+}
+
+#[test]
+fn test_string_byte_slice() {
+	const S: String<0x8> = string!("a\u{00E6}\u{1F480}");
+
+	const {
+		assert!(matches!(S.byte_slice(0x2..0x5), Some(&[0xA6, 0xF0, 0x9F])));
+		assert!(matches!(S.byte_slice(0x7..0x7), Some(&[])));
+		assert!(S.byte_slice(0x6..0x8).is_none());
+	};
+
+	assert_eq!(S.byte_slice(0x0..S.len()), Some(S.as_bytes()));
+
+	#[allow(clippy::reversed_empty_ranges)]
+	let range = 0x3..0x2;
+	assert_eq!(S.byte_slice(range), None);
+}
+
+#[test]
+fn test_string_append_str() {
+	// The fast path must behave identically to inser-
+	// ting at the end of the string.
+
+	let mut a: String<0x8> = string!("abc");
+	let mut b: String<0x8> = string!("abc");
+
+	for other in ["de", "\u{00E6}", "xyz", "", "f", "gh"] {
+		let old = a;
+
+		let index = b.len();
+
+		let result = a.append_str(other);
+		assert_eq!(result, b.insert_str(index, other));
+		assert_eq!(a, b);
+
+		if result.is_err() {
+			assert_eq!(a, old);
+		}
+	}
+
+	assert_eq!(a, "abcde\u{00E6}f");
+
+	let mut a: String<0x8> = string!("abcdefg");
+	let mut b: String<0x8> = string!("abcdefg");
+
+	assert_eq!(a.append_str("xyz"), Err(LengthError { remaining: 0x1, count: 0x3 }));
+	assert_eq!(b.insert_str(0x7, "xyz"), Err(LengthError { remaining: 0x1, count: 0x3 }));
+	assert_eq!(a, "abcdefg");
+	assert_eq!(b, "abcdefg");
+}
+
+#[test]
+fn test_string_first_last_char() {
+	const PATH:  String<0x10> = string!("\u{00E6}/usr/\u{1F480}");
+	const EMPTY: String<0x10> = String::new();
+
+	const {
+		assert!(matches!(PATH.first_char(), Some('\u{00E6}')));
+		assert!(matches!(PATH.last_char(),  Some('\u{1F480}')));
+
+		assert!(!matches!(PATH.last_char(), Some('/')));
+
+		assert!(EMPTY.first_char().is_none());
+		assert!(EMPTY.last_char().is_none());
+	};
+
+	let s: String<0x1> = string!("a");
+
+	assert_eq!(s.first_char(), Some('a'));
+	assert_eq!(s.last_char(),  Some('a'));
+}
+
+#[test]
+fn test_string_c_str() {
+	let mut s: String<0x8> = string!("conststr");
+	assert_eq!(s.to_c_string(), Err(CStrError::SmallBuffer(LengthError { remaining: 0x0, count: 0x1 })));
+	assert_eq!(s.as_c_str(), Err(CStrError::NotNulTerminated));
+
+	let mut s: String<0x8> = string!("const");
+	assert_eq!(s.to_c_string(), Ok(c"const"));
+	assert_eq!(s, "const");
+
+	let s: String<0x8> = string!("const\0");
+	assert_eq!(s.as_c_str(), Ok(c"const"));
+
+	let mut s: String<0x8> = string!("con\0st");
+	assert_eq!(s.to_c_string(), Err(CStrError::InteriorNul(0x3)));
+
+	let s: String<0x8> = string!("con\0st\0");
+	assert_eq!(s.as_c_str(), Err(CStrError::InteriorNul(0x3)));
+
+	let s: String<0x8> = string!("\0");
+	assert_eq!(s.as_c_str(), Ok(c""));
+}
+
+#[test]
+fn test_string_swap_chars() {
+	let mut s: String<0x10> = string!("abcd");
+
+	s.swap_chars(0x0, 0x3);
+	assert_eq!(s, "dbca");
+
+	s.swap_chars(0x2, 0x1);
+	assert_eq!(s, "dcba");
+
+	s.swap_chars(0x1, 0x1);
+	assert_eq!(s, "dcba");
+
+	let mut s: String<0x10> = string!("a\u{00E6}b\u{1F480}");
+
+	s.swap_chars(0x0, 0x3);
+	assert_eq!(s, "\u{1F480}\u{00E6}ba");
+	assert_eq!(s.len(), 0x8);
+
+	s.swap_chars(0x1, 0x3);
+	assert_eq!(s, "\u{1F480}ab\u{00E6}");
+	assert_eq!(s.len(), 0x8);
+
+	s.swap_chars(0x0, 0x1);
+	assert_eq!(s, "a\u{1F480}b\u{00E6}");
+	assert_eq!(str::from_utf8(s.as_bytes()), Ok(s.as_str()));
+}
+
+#[test]
+#[should_panic]
+fn test_string_swap_chars_out_of_range() {
+	let mut s: String<0x10> = string!("a\u{00E6}b");
+	s.swap_chars(0x0, 0x3);
+}
+
+#[test]
+fn test_string_dedup() {
+	let mut s: String<0x10> = string!("a   b  c ");
+
+	s.dedup_char(' ');
+	assert_eq!(s, "a b c ");
+
+	let mut s: String<0x20> = string!("\u{00E6}\u{00E6}\u{00E6}a\u{1F480}\u{1F480}aa");
+
+	s.dedup_char('\u{00E6}');
+	assert_eq!(s, "\u{00E6}a\u{1F480}\u{1F480}aa");
+	assert_eq!(s.len(), 0xD);
+
+	s.dedup_by(|a, b| a == b);
+	assert_eq!(s, "\u{00E6}a\u{1F480}a");
+	assert_eq!(s.len(), 0x8);
+	assert_eq!(str::from_utf8(s.as_bytes()), Ok(s.as_str()));
+
+	let mut s: String<0x10> = string!("aAbBBc");
+
+	s.dedup_by(|a, b| a.eq_ignore_ascii_case(&b));
+	assert_eq!(s, "abc");
+}
+
+#[test]
+fn test_string_push_front() {
+	let mut s: String<0x8> = string!("str");
+
+	s.push_str_front("const").unwrap();
+	assert_eq!(s, "conststr");
+
+	let mut s: String<0x8> = string!("bc");
+
+	s.push_front('a').unwrap();
+	s.push_front('\u{00E6}').unwrap();
+	s.push_str_front("\u{00F8}").unwrap();
+	assert_eq!(s, "\u{00F8}\u{00E6}abc");
+
+	assert_eq!(s.push_front('\u{00E5}'), Err(LengthError { remaining: 0x1, count: 0x2 }));
+	assert_eq!(s.push_str_front("de"),   Err(LengthError { remaining: 0x1, count: 0x2 }));
+	assert_eq!(s, "\u{00F8}\u{00E6}abc");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_char_boundaries() {
+	use alloc::vec::Vec;
+
+	let s: String<0x10> = string!("a\u{00E6}\u{2020}\u{1F480}b");
+
+	let boundaries: Vec<usize> = s.char_boundaries().collect();
+	assert_eq!(boundaries, [0x0, 0x1, 0x3, 0x6, 0xA, 0xB]);
+	assert!(boundaries.iter().all(|&index| s.is_char_boundary(index)));
+
+	let (min, max) = s.char_boundaries().size_hint();
+	assert!(min <= boundaries.len() && max == Some(0xC));
+
+	let s: String<0x10> = String::new();
+
+	let boundaries: Vec<usize> = s.char_boundaries().collect();
+	assert_eq!(boundaries, [0x0]);
+
+	const COUNT: usize = {
+		let s: String<0x4> = string!("\u{00E6}\u{00F8}");
+		let mut iter = s.char_boundaries();
+
+		let mut count = 0x0;
+		while iter.next_boundary().is_some() {
+			count += 0x1;
+		}
+
+		count
+	};
+
+	assert_eq!(COUNT, 0x3);
+}
+
+#[test]
+fn test_string_set_len() {
+	let mut s: String<0x8> = String::new();
+
+	// SAFETY: We only write within the capacity and
+	// only write valid UTF-8.
+	unsafe {
+		let src = "\u{00E6}bc".as_ptr();
+		s.as_mut_ptr().copy_from_nonoverlapping(src, 0x4);
+
+		s.set_len(0x4);
+	}
+
+	assert_eq!(s, "\u{00E6}bc");
+
+	// SAFETY: Shortening to a boundary is always valid.
+	unsafe { s.set_len(0x2) };
+
+	assert_eq!(s, "\u{00E6}");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn test_string_set_len_out_of_bounds() {
+	let mut s: String<0x4> = String::new();
+
+	// NOTE: This trips the debug assertion before the
+	// length is ever used.
+	unsafe { s.set_len(0x5) };
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn test_string_set_len_non_boundary() {
+	let mut s: String<0x4> = string!("\u{00E6}");
+
+	unsafe { s.set_len(0x1) };
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_string_hash_map_lookup() {
+	use std::collections::HashMap;
+
+	let mut map: HashMap<String<0x8>, u32> = HashMap::new();
+
+	map.insert(string!("foo"),      0x1);
+	map.insert(string!("\u{00E6}"), 0x2);
+
+	assert_eq!(map.get("foo"),      Some(&0x1));
+	assert_eq!(map.get("\u{00E6}"), Some(&0x2));
+	assert_eq!(map.get("bar"),      None);
+
+	let key: &[u8] = b"\xC3\xA6";
+	assert_eq!(str::from_utf8(key).ok().and_then(|key| map.get(key)), Some(&0x2));
+}
+
+#[test]
+fn test_string_split_whitespace_into() {
+	const S: String<0x10> = string!("  foo   bar baz ");
+
+	const TOKENS: ([&str; 0x3], usize) = S.split_whitespace_into();
+	assert_eq!(TOKENS, (["foo", "bar", "baz"], 0x3));
+
+	let (tokens, count) = S.split_whitespace_into::<0x2>();
+	assert_eq!((tokens, count), (["foo", "bar"], 0x2));
+
+	let (tokens, count) = S.split_whitespace_into::<0x5>();
+	assert_eq!((tokens, count), (["foo", "bar", "baz", "", ""], 0x3));
+
+	let s: String<0x10> = string!("\u{00A0}\u{00E6}\u{3000}\u{00F8}\t");
+
+	let (tokens, count) = s.split_whitespace_into::<0x4>();
+	assert_eq!(&tokens[..count], ["\u{00E6}", "\u{00F8}"]);
+
+	let s: String<0x10> = string!(" \t\n ");
+
+	let (_, count) = s.split_whitespace_into::<0x4>();
+	assert_eq!(count, 0x0);
+
+	let (_, count) = S.split_whitespace_into::<0x0>();
+	assert_eq!(count, 0x0);
+}
+
+#[test]
+fn test_string_insert_str_checked() {
+	let mut s: String<0x8> = string!("abcd");
+
+	assert_eq!(s.insert_str_checked(0x2, "xy"), Ok(()));
+	assert_eq!(s, "abxycd");
+
+	assert_eq!(s.insert_str_checked(0x0, "123"), Err("3"));
+	assert_eq!(s, "12abxycd");
+
+	assert_eq!(s.insert_str_checked(0x8, "z"), Err("z"));
+	assert_eq!(s, "12abxycd");
+
+	// The inserted prefix must not split characters.
+
+	let mut s: String<0x8> = string!("abcde");
+
+	assert_eq!(s.insert_str_checked(0x3, "\u{00E6}\u{00F8}\u{00E5}"), Err("\u{00F8}\u{00E5}"));
+	assert_eq!(s, "abc\u{00E6}de");
+
+	let mut s: String<0x8> = string!("abcdefg");
+
+	assert_eq!(s.insert_str_checked(0x7, "\u{1F480}!"), Err("\u{1F480}!"));
+	assert_eq!(s, "abcdefg");
+
+	// Spill the remainder into another buffer.
+
+	let mut a: String<0x4> = string!("[]");
+	let mut b: String<0x4> = string!();
+
+	let rest = a.insert_str_checked(0x1, "\u{00E6}\u{00F8}").unwrap_err();
+	assert_eq!(b.insert_str_checked(0x0, rest), Ok(()));
+
+	assert_eq!(a, "[\u{00E6}]");
+	assert_eq!(b, "\u{00F8}");
+}
+
+#[test]
+#[should_panic]
+fn test_string_insert_str_checked_non_boundary() {
+	let mut s: String<0x8> = string!("\u{00E6}");
+
+	let _ = s.insert_str_checked(0x1, "a");
+}
+
+#[test]
+fn test_string_eq_reflexive() {
+	let s: String<0x8> = string!("hi");
+
+	assert!(s == *"hi");
+	assert!(*"hi" == s);
+
+	assert!(s == "hi");
+	assert!("hi" == s);
+
+	assert!(s != "ho");
+	assert!("ho" != s);
+
+	assert_eq!(s, "hi");
+	assert_eq!("hi", s);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string_eq_reflexive_cow() {
+	use alloc::borrow::Cow;
+
+	let s: String<0x8> = string!("hi");
+
+	let borrowed: Cow<str> = Cow::Borrowed("hi");
+	let owned:    Cow<str> = Cow::Owned("ho".into());
+
+	assert!(s == borrowed);
+	assert!(borrowed == s);
+
+	assert!(s != owned);
+	assert!(owned != s);
+
+	assert_eq!(borrowed, s);
+	assert_ne!(owned, s);
+}
+
+#[test]
+fn test_string_capacity() {
+	const {
+		assert!(String::<0x0>::CAPACITY  == 0x0);
+		assert!(String::<0x10>::CAPACITY == 0x10);
+
+		assert!(max_capacity(0x4, 0x8) == 0x8);
+		assert!(max_capacity(0x8, 0x4) == 0x8);
+		assert!(min_capacity(0x4, 0x8) == 0x4);
+		assert!(min_capacity(0x8, 0x8) == 0x8);
+	}
+
+	const CAPACITY: usize = max_capacity(String::<0x4>::CAPACITY, String::<0x6>::CAPACITY);
+
+	let mut s: String<CAPACITY> = string!("abcd");
+	s.push_str("ef").unwrap();
+
+	assert_eq!(s, "abcdef");
+}
+
+#[test]
+fn test_string_keep_range() {
+	let mut s: String<0x10> = string!("\u{00E6}bl\u{00F8}d\u{00E5}");
+
+	s.keep_range(0x2..0x6);
+	assert_eq!(s, "bl\u{00F8}");
+
+	s.keep_range(..);
+	assert_eq!(s, "bl\u{00F8}");
+
+	s.keep_range(0x1..=0x1);
+	assert_eq!(s, "l");
+
+	s.keep_range(0x1..);
+	assert_eq!(s, "");
+
+	let mut s: String<0x8> = string!("conststr");
+
+	s.keep_range(..0x5);
+	assert_eq!(s, "const");
+}
+
+#[test]
+#[should_panic]
+fn test_string_keep_range_non_boundary() {
+	let mut s: String<0x8> = string!("\u{00E6}\u{00F8}");
+
+	s.keep_range(0x1..);
+}
+
+#[test]
+#[should_panic]
+fn test_string_keep_range_out_of_bounds() {
+	let mut s: String<0x8> = string!("abc");
+
+	s.keep_range(..0x4);
+}
@@ -0,0 +1,22 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#![cfg(feature = "zeroize")]
+
+use crate::String;
+
+use zeroize::Zeroize;
+
+#[cfg_attr(doc, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> Zeroize for String<N> {
+	/// Zeroes the entire buffer of the string and sets its length to zero.
+	///
+	/// Note that this includes octets past the current length of the string, which may contain data from previous operations.
+	///
+	/// As [`String`] implements [`Copy`], any copies of the string (including those implicitly made by moves) are not affected by this method.
+	/// For the same reason, [`ZeroizeOnDrop`](zeroize::ZeroizeOnDrop) cannot be implemented.
+	#[inline]
+	fn zeroize(&mut self) {
+		self.len = 0x0;
+		self.buf.zeroize();
+	}
+}
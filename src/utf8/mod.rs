@@ -1,7 +1,77 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+//! UTF-8 utilities.
+
 mod test;
 
+/// Counts the octets of `data` that are part of invalid UTF-8 sequences.
+///
+/// Each octet that cannot start a valid sequence -- or that starts a sequence which is not properly continued -- is counted as invalid.
+/// Continuation octets of otherwise valid sequences are not counted.
+/// A result of zero therefore means that `data` is valid UTF-8.
+#[inline]
+#[must_use]
+pub const fn count_invalid_utf8(data: &[u8]) -> usize {
+	let len = data.len();
+
+	let mut count = 0x0;
+
+	let mut i = 0x0;
+	while i < len {
+		let prefix = data[i];
+
+		// Stray continuation octets would otherwise trip
+		// `utf8_char_len`.
+		if prefix & 0b11000000 == 0b10000000 {
+			count += 0x1;
+			i     += 0x1;
+
+			continue;
+		}
+
+		let char_len = utf8_char_len(prefix);
+
+		// Reject overlong encodings, surrogates, and code
+		// points past U+10FFFF by narrowing the range of
+		// the second octet.
+		let (min, max) = match prefix {
+			0x00..=0x7F => (0x00, 0xFF),
+			0xE0        => (0xA0, 0xBF),
+			0xED        => (0x80, 0x9F),
+			0xF0        => (0x90, 0xBF),
+			0xF4        => (0x80, 0x8F),
+
+			0xC2..=0xDF | 0xE1..=0xEC | 0xEE..=0xEF | 0xF1..=0xF3 => (0x80, 0xBF),
+
+			_ => (0xFF, 0x00),
+		};
+
+		let mut is_valid = i + char_len <= len;
+
+		let mut j = 0x1;
+		while is_valid && j < char_len {
+			let octet = data[i + j];
+
+			is_valid = if j == 0x1 {
+				octet >= min && octet <= max
+			} else {
+				octet & 0b11000000 == 0b10000000
+			};
+
+			j += 0x1;
+		}
+
+		if is_valid {
+			i += char_len;
+		} else {
+			count += 0x1;
+			i     += 0x1;
+		}
+	}
+
+	count
+}
+
 #[must_use]
 #[track_caller]
 pub(crate) const fn decode_utf8(buf: &str, index: usize) -> (char, usize) {
@@ -91,3 +161,74 @@ pub(crate) const fn utf8_char_len(mut prefix: u8) -> usize {
 		}
 	}
 }
+
+/// Checks if a character has the `White_Space` property.
+///
+/// This is equivalent to [`char::is_whitespace`], which is not usable in constant expressions on our MSRV.
+#[inline]
+#[must_use]
+pub(crate) const fn is_whitespace(c: char) -> bool {
+	matches!(
+		c,
+		'\u{0009}'..='\u{000D}'
+			| '\u{0020}'
+			| '\u{0085}'
+			| '\u{00A0}'
+			| '\u{1680}'
+			| '\u{2000}'..='\u{200A}'
+			| '\u{2028}'
+			| '\u{2029}'
+			| '\u{202F}'
+			| '\u{205F}'
+			| '\u{3000}'
+	)
+}
+
+/// Removes leading whitespace from a string.
+#[inline]
+#[must_use]
+pub(crate) const fn trim_start(s: &str) -> &str {
+	let len = s.len();
+
+	let mut index = 0x0;
+	while index < len {
+		let (c, char_len) = decode_utf8(s, index);
+
+		if !is_whitespace(c) {
+			break;
+		}
+
+		index += char_len;
+	}
+
+	let (_, s) = s.split_at(index);
+	s
+}
+
+/// Removes trailing whitespace from a string.
+#[inline]
+#[must_use]
+pub(crate) const fn trim_end(s: &str) -> &str {
+	let buf = s.as_bytes();
+
+	let mut index = s.len();
+	while index > 0x0 {
+		// Find the start of the last character.
+
+		let mut start = index - 0x1;
+		while buf[start] & 0b11000000 == 0b10000000 {
+			start -= 0x1;
+		}
+
+		let (c, _) = decode_utf8(s, start);
+
+		if !is_whitespace(c) {
+			break;
+		}
+
+		index = start;
+	}
+
+	let (s, _) = s.split_at(index);
+	s
+}
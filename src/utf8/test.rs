@@ -2,7 +2,21 @@
 
 #![cfg(test)]
 
-use crate::utf8::{decode_utf8, utf8_char_len};
+use crate::utf8::{count_invalid_utf8, decode_utf8, is_whitespace, utf8_char_len};
+
+#[test]
+fn test_count_invalid_utf8() {
+	assert_eq!(count_invalid_utf8(b""), 0x0);
+	assert_eq!(count_invalid_utf8("l\u{00E6}s \u{1F54B}".as_bytes()), 0x0);
+
+	assert_eq!(count_invalid_utf8(b"a\xFFb\x80c"), 0x2);
+	assert_eq!(count_invalid_utf8(b"\xE2\x82a"), 0x2);
+	assert_eq!(count_invalid_utf8(b"\xC0\xAF"), 0x2);
+	assert_eq!(count_invalid_utf8(b"\xED\xA0\x80"), 0x3);
+	assert_eq!(count_invalid_utf8(b"\xF0\x9F\x95"), 0x3);
+
+	const { assert!(count_invalid_utf8(b"\xFE\xFF") == 0x2) };
+}
 
 #[test]
 fn test_decode_utf8() {
@@ -36,3 +50,10 @@ fn test_utf8_char_len() {
 	assert_eq!(utf8_char_len(0b11111011u8), 0x5);
 	assert_eq!(utf8_char_len(0b11111111u8), 0x6);
 }
+
+#[test]
+fn test_is_whitespace() {
+	for c in '\0'..=char::MAX {
+		assert_eq!(is_whitespace(c), c.is_whitespace(), "{c:?}");
+	}
+}